@@ -8,9 +8,57 @@ use std::ops::Rem;
 use std::ops::Sub;
 use std::ops::SubAssign;
 
+mod blend_mode;
+pub use self::blend_mode::*;
+
+mod colour_f32;
+pub use self::colour_f32::*;
+
 mod colour_parse_error;
 pub use self::colour_parse_error::*;
 
+mod colour_space;
+pub use self::colour_space::*;
+
+mod compositing;
+
+mod contrast;
+
+mod css;
+
+mod gradient;
+pub use self::gradient::*;
+
+mod hsla;
+pub use self::hsla::*;
+
+mod hsva;
+pub use self::hsva::*;
+
+mod lab;
+pub use self::lab::*;
+
+mod linear_rgba;
+pub use self::linear_rgba::*;
+
+mod oklab;
+pub use self::oklab::*;
+
+mod oklch;
+pub use self::oklch::*;
+
+mod pixel_format;
+pub use self::pixel_format::*;
+
+mod rgba;
+pub use self::rgba::*;
+
+mod text_size;
+pub use self::text_size::*;
+
+mod wcag_level;
+pub use self::wcag_level::*;
+
 pub type Color = Colour;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -177,6 +225,10 @@ impl Colour {
         }
     }
 
+    fn f32_to_rounded_u8(val: f32) -> u8 {
+        (val.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
     fn f32_to_rgba_u32(val: f32) -> u32 {
         if val >= 1.0 {
             255
@@ -206,6 +258,17 @@ impl Colour {
         }
     }
 
+    /// Like `new_from_f32s`, but rounds each component to the nearest value,
+    /// so converting to and from f32s gives back the same colour.
+    pub(crate) fn new_from_f32s_rounded(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self::new_from_u8s(
+            Colour::f32_to_rounded_u8(red),
+            Colour::f32_to_rounded_u8(green),
+            Colour::f32_to_rounded_u8(blue),
+            Colour::f32_to_rounded_u8(alpha),
+        )
+    }
+
     pub const fn new_from_u32s(red: u32, green: u32, blue: u32, alpha: u32) -> Self {
         Self {
             rgba: Colour::rgba_u32s_to_rgba_hex(red, green, blue, alpha),
@@ -286,6 +349,89 @@ impl Colour {
         result
     }
 
+    pub fn to_hsva(self) -> Hsva {
+        Hsva::from(self)
+    }
+
+    pub fn to_hsla(self) -> Hsla {
+        Hsla::from(self)
+    }
+
+    pub fn to_linear_rgba(self) -> LinearRgba {
+        LinearRgba::from(self)
+    }
+
+    pub fn to_oklab(self) -> Oklab {
+        Oklab::from(self)
+    }
+
+    pub fn to_oklch(self) -> Oklch {
+        Oklch::from(self)
+    }
+
+    pub fn to_lab(self) -> Lab {
+        Lab::from(self)
+    }
+
+    pub fn to_colour_f32(self) -> ColourF32 {
+        ColourF32::from(self)
+    }
+
+    /// Returns a copy with the HSL lightness increased by the amount given.
+    /// The amount is from 0.0 to 1.0.
+    pub fn lighten(self, amount: f32) -> Self {
+        self.to_hsla().lighten(amount).to_colour()
+    }
+
+    /// Returns a copy with the HSL lightness decreased by the amount given.
+    /// The amount is from 0.0 to 1.0.
+    pub fn darken(self, amount: f32) -> Self {
+        self.to_hsla().darken(amount).to_colour()
+    }
+
+    /// Returns a copy with the HSL saturation increased by the amount given.
+    /// The amount is from 0.0 to 1.0.
+    pub fn saturate(self, amount: f32) -> Self {
+        self.to_hsla().saturate(amount).to_colour()
+    }
+
+    /// Returns a copy with the HSL saturation decreased by the amount given.
+    /// The amount is from 0.0 to 1.0.
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.to_hsla().desaturate(amount).to_colour()
+    }
+
+    /// Returns a copy moved around the colour wheel,
+    /// where an amount of 1.0 is a full turn.
+    pub fn rotate_hue(self, amount: f32) -> Self {
+        self.to_hsva().rotate_hue(amount).to_colour()
+    }
+
+    /// Mixes the two colours within the colour space given.
+    /// An amount of 0.0 is this colour, and 1.0 is the other.
+    pub fn mix_in(self, space: ColourSpace, other: Self, mut amount: f32) -> Self {
+        amount = amount.clamp(0.0, 1.0);
+
+        match space {
+            ColourSpace::Srgb => self.mix(other, amount),
+            ColourSpace::LinearSrgb => self
+                .to_linear_rgba()
+                .mix(other.to_linear_rgba(), amount)
+                .to_colour(),
+            ColourSpace::Oklab => self.to_oklab().mix(other.to_oklab(), amount).to_colour(),
+            ColourSpace::Oklch => self.to_oklch().mix(other.to_oklch(), amount).to_colour(),
+            ColourSpace::Lab => self.to_lab().mix(other.to_lab(), amount).to_colour(),
+        }
+    }
+
+    /// The perceived difference between two colours, using CIEDE2000.
+    ///
+    /// A difference below 1.0 is not noticeable to most people.
+    /// Alpha is ignored.
+    pub fn delta_e(self, other: Self) -> f32 {
+        self.to_lab().delta_e(other.to_lab())
+    }
+
     pub fn replace_alpha_f32(self, alpha: f32) -> Self {
         self.replace_alpha_u32(Colour::f32_to_rgba_u32(alpha))
     }
@@ -520,6 +666,122 @@ mod new_rgba_hex {
     }
 }
 
+#[cfg(test)]
+mod lighten {
+    use super::*;
+
+    #[test]
+    fn it_should_change_lightness() {
+        assert_eq!(Colour::RED.lighten(0.2), Colour::LIGHT_RED);
+        assert_eq!(Colour::LIGHT_RED.darken(0.2), Colour::RED);
+        assert_eq!(Colour::GREY.lighten(1.0), Colour::WHITE);
+        assert_eq!(Colour::GREY.darken(1.0), Colour::BLACK);
+    }
+
+    #[test]
+    fn it_should_keep_alpha() {
+        let colour = Colour::new_from_u8s(10, 20, 30, 40);
+
+        assert_eq!(colour.lighten(0.5).alpha_u8(), 40);
+    }
+}
+
+#[cfg(test)]
+mod saturate {
+    use super::*;
+
+    #[test]
+    fn it_should_change_saturation() {
+        let colour = Colour::new_from_u8s(160, 96, 96, 255);
+
+        assert_eq!(
+            colour.desaturate(1.0),
+            Colour::new_from_u8s(128, 128, 128, 255)
+        );
+        assert_eq!(colour.saturate(1.0), Colour::new_from_u8s(255, 1, 1, 255));
+    }
+}
+
+#[cfg(test)]
+mod rotate_hue {
+    use super::*;
+
+    #[test]
+    fn it_should_move_around_colour_wheel() {
+        assert_eq!(Colour::RED.rotate_hue(1.0 / 3.0), Colour::GREEN);
+        assert_eq!(Colour::RED.rotate_hue(-1.0 / 3.0), Colour::BLUE);
+        assert_eq!(Colour::GREY.rotate_hue(0.5), Colour::GREY);
+    }
+
+    #[test]
+    fn it_should_stay_red_when_rotated_just_below_zero() {
+        assert_eq!(Colour::RED.rotate_hue(-1e-9), Colour::RED);
+    }
+}
+
+#[cfg(test)]
+mod mix_in {
+    use super::*;
+
+    #[test]
+    fn it_should_match_mix_in_srgb() {
+        let colour = Colour::new_from_u8s(200, 100, 50, 255);
+
+        assert_eq!(
+            colour.mix_in(ColourSpace::Srgb, Colour::BLUE, 0.3),
+            colour.mix(Colour::BLUE, 0.3)
+        );
+    }
+
+    #[test]
+    fn it_should_return_ends() {
+        let spaces = [
+            ColourSpace::Srgb,
+            ColourSpace::LinearSrgb,
+            ColourSpace::Oklab,
+            ColourSpace::Oklch,
+            ColourSpace::Lab,
+        ];
+
+        for space in spaces {
+            assert_eq!(Colour::RED.mix_in(space, Colour::BLUE, 0.0), Colour::RED);
+            assert_eq!(Colour::RED.mix_in(space, Colour::BLUE, 1.0), Colour::BLUE);
+        }
+    }
+
+    #[test]
+    fn it_should_blend_brighter_in_linear_space() {
+        let srgb = Colour::BLACK.mix_in(ColourSpace::Srgb, Colour::WHITE, 0.5);
+        let linear = Colour::BLACK.mix_in(ColourSpace::LinearSrgb, Colour::WHITE, 0.5);
+
+        assert_eq!(srgb.red_u8(), 127);
+        assert_eq!(linear.red_u8(), 188);
+    }
+
+    #[test]
+    fn it_should_keep_chroma_in_oklch() {
+        let oklab = Colour::RED.mix_in(ColourSpace::Oklab, Colour::CYAN, 0.5);
+        let oklch = Colour::RED.mix_in(ColourSpace::Oklch, Colour::CYAN, 0.5);
+
+        assert!(oklab.to_oklch().chroma < 0.1);
+        assert!(oklch.to_oklch().chroma > 0.15);
+    }
+}
+
+#[cfg(test)]
+mod delta_e {
+    use super::*;
+
+    #[test]
+    fn it_should_be_small_for_similar_colours() {
+        let colour = Colour::new_from_u8s(100, 150, 200, 255);
+
+        assert_eq!(colour.delta_e(colour), 0.0);
+        assert!(colour.delta_e(Colour::new_from_u8s(101, 150, 200, 255)) < 1.0);
+        assert!(Colour::RED.delta_e(Colour::GREEN) > 50.0);
+    }
+}
+
 #[cfg(test)]
 mod replace_alpha_f32 {
     use super::*;
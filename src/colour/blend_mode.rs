@@ -0,0 +1,152 @@
+/// The separable blend modes, which combine each colour channel on its own.
+///
+/// See: https://www.w3.org/TR/compositing-1/#blending
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Uses the source colour, ignoring the backdrop.
+    #[default]
+    Normal,
+
+    /// Darkens, as the two colours are multiplied.
+    Multiply,
+
+    /// Lightens, the opposite of `Multiply`.
+    Screen,
+
+    /// `Multiply` or `Screen`, depending on the backdrop.
+    Overlay,
+
+    /// Keeps the darkest of the two.
+    Darken,
+
+    /// Keeps the lightest of the two.
+    Lighten,
+
+    /// Brightens the backdrop to reflect the source.
+    ColourDodge,
+
+    /// Darkens the backdrop to reflect the source.
+    ColourBurn,
+
+    /// `Multiply` or `Screen`, depending on the source.
+    HardLight,
+
+    /// A softer version of `HardLight`.
+    SoftLight,
+
+    /// Subtracts the darker of the two from the lighter.
+    Difference,
+
+    /// Like `Difference`, but with lower contrast.
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Blends a single channel, where each value is from 0.0 to 1.0.
+    pub fn apply(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+            BlendMode::Overlay => BlendMode::HardLight.apply(source, backdrop),
+            BlendMode::Darken => backdrop.min(source),
+            BlendMode::Lighten => backdrop.max(source),
+            BlendMode::ColourDodge => {
+                if backdrop <= 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            BlendMode::ColourBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if source <= 0.5 {
+                    BlendMode::Multiply.apply(backdrop, 2.0 * source)
+                } else {
+                    BlendMode::Screen.apply(backdrop, 2.0 * source - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if source <= 0.5 {
+                    backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        backdrop.sqrt()
+                    };
+
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            BlendMode::Difference => (backdrop - source).abs(),
+            BlendMode::Exclusion => backdrop + source - 2.0 * backdrop * source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod apply {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_blend_with_black_and_white() {
+        assert_eq!(BlendMode::Multiply.apply(0.6, 1.0), 0.6);
+        assert_eq!(BlendMode::Multiply.apply(0.6, 0.0), 0.0);
+        assert_eq!(BlendMode::Screen.apply(0.6, 0.0), 0.6);
+        assert_eq!(BlendMode::Screen.apply(0.6, 1.0), 1.0);
+        assert_eq!(BlendMode::Difference.apply(0.25, 1.0), 0.75);
+        assert_eq!(BlendMode::Exclusion.apply(0.25, 1.0), 0.75);
+    }
+
+    #[test]
+    fn it_should_blend_mid_values() {
+        assert_approx_eq!(BlendMode::Multiply.apply(0.5, 0.5), 0.25);
+        assert_approx_eq!(BlendMode::Screen.apply(0.5, 0.5), 0.75);
+        assert_approx_eq!(BlendMode::Overlay.apply(0.25, 0.5), 0.25);
+        assert_approx_eq!(BlendMode::Overlay.apply(0.75, 0.5), 0.75);
+        assert_approx_eq!(BlendMode::HardLight.apply(0.5, 0.25), 0.25);
+        assert_approx_eq!(BlendMode::HardLight.apply(0.5, 0.75), 0.75);
+        assert_approx_eq!(BlendMode::Darken.apply(0.2, 0.7), 0.2);
+        assert_approx_eq!(BlendMode::Lighten.apply(0.2, 0.7), 0.7);
+        assert_approx_eq!(BlendMode::Exclusion.apply(0.5, 0.5), 0.5);
+    }
+
+    #[test]
+    fn it_should_dodge_and_burn() {
+        assert_approx_eq!(BlendMode::ColourDodge.apply(0.25, 0.5), 0.5);
+        assert_approx_eq!(BlendMode::ColourDodge.apply(0.75, 0.5), 1.0);
+        assert_eq!(BlendMode::ColourDodge.apply(0.0, 1.0), 0.0);
+        assert_eq!(BlendMode::ColourDodge.apply(0.5, 1.0), 1.0);
+
+        assert_approx_eq!(BlendMode::ColourBurn.apply(0.75, 0.5), 0.5);
+        assert_approx_eq!(BlendMode::ColourBurn.apply(0.25, 0.5), 0.0);
+        assert_eq!(BlendMode::ColourBurn.apply(1.0, 0.0), 1.0);
+        assert_eq!(BlendMode::ColourBurn.apply(0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn it_should_soft_light() {
+        assert_approx_eq!(BlendMode::SoftLight.apply(0.5, 0.5), 0.5);
+        assert_approx_eq!(BlendMode::SoftLight.apply(0.5, 0.0), 0.25);
+        assert_approx_eq!(BlendMode::SoftLight.apply(0.25, 1.0), 0.5);
+        assert_approx_eq!(BlendMode::SoftLight.apply(0.1, 1.0), 0.296);
+    }
+
+    #[test]
+    fn it_should_keep_normal_source() {
+        assert_eq!(BlendMode::Normal.apply(0.2, 0.7), 0.7);
+        assert_eq!(BlendMode::default(), BlendMode::Normal);
+    }
+}
@@ -0,0 +1,349 @@
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Rem;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use crate::Colour;
+
+/// A colour with a full f32 for each channel.
+///
+/// Channels are the same as `Colour::red_f32` and friends,
+/// where 0.0 to 1.0 is the displayable range.
+/// Unlike `Colour`, values are never clamped, so they can go above 1.0 for HDR,
+/// and below 0.0 in the middle of a calculation.
+///
+/// Use a tone mapping function to bring HDR values back into range.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ColourF32 {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+impl ColourF32 {
+    pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Converts to a `Colour`, clamping each channel to 0.0 to 1.0.
+    pub fn to_colour(self) -> Colour {
+        Colour::new_from_f32s_rounded(self.red, self.green, self.blue, self.alpha)
+    }
+
+    /// Returns a copy with each channel clamped to 0.0 to 1.0.
+    pub fn clamp(self) -> Self {
+        self.map_rgb(|n| n.clamp(0.0, 1.0))
+            .with_alpha(self.alpha.clamp(0.0, 1.0))
+    }
+
+    /// The largest of the red, green, and blue channels.
+    pub fn max_channel(self) -> f32 {
+        self.red.max(self.green).max(self.blue)
+    }
+
+    /// Maps HDR values into 0.0 to 1.0 using the simple Reinhard operator.
+    /// Alpha is unchanged.
+    pub fn tone_map_reinhard(self) -> Self {
+        self.map_rgb(|n| {
+            let n = n.max(0.0);
+            n / (1.0 + n)
+        })
+    }
+
+    /// Maps HDR values into 0.0 to 1.0, using Krzysztof Narkowicz's fit of the ACES filmic curve.
+    /// This gives more contrast than Reinhard. Alpha is unchanged.
+    pub fn tone_map_aces(self) -> Self {
+        self.map_rgb(|n| {
+            let n = n.max(0.0);
+            let mapped = (n * (2.51 * n + 0.03)) / (n * (2.43 * n + 0.59) + 0.14);
+            mapped.clamp(0.0, 1.0)
+        })
+    }
+
+    fn map_rgb<F>(self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::new(f(self.red), f(self.green), f(self.blue), self.alpha)
+    }
+
+    fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl From<Colour> for ColourF32 {
+    fn from(colour: Colour) -> Self {
+        Self::new(
+            colour.red_f32(),
+            colour.green_f32(),
+            colour.blue_f32(),
+            colour.alpha_f32(),
+        )
+    }
+}
+
+impl From<ColourF32> for Colour {
+    fn from(colour: ColourF32) -> Self {
+        colour.to_colour()
+    }
+}
+
+impl Add<Self> for ColourF32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.red + other.red,
+            self.green + other.green,
+            self.blue + other.blue,
+            self.alpha + other.alpha,
+        )
+    }
+}
+
+impl AddAssign<Self> for ColourF32 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Self> for ColourF32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.red - other.red,
+            self.green - other.green,
+            self.blue - other.blue,
+            self.alpha - other.alpha,
+        )
+    }
+}
+
+impl SubAssign<Self> for ColourF32 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Self> for ColourF32 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.red * other.red,
+            self.green * other.green,
+            self.blue * other.blue,
+            self.alpha * other.alpha,
+        )
+    }
+}
+
+impl MulAssign<Self> for ColourF32 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div<Self> for ColourF32 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::new(
+            self.red / other.red,
+            self.green / other.green,
+            self.blue / other.blue,
+            self.alpha / other.alpha,
+        )
+    }
+}
+
+impl DivAssign<Self> for ColourF32 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Rem<Self> for ColourF32 {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Self::new(
+            self.red % other.red,
+            self.green % other.green,
+            self.blue % other.blue,
+            self.alpha % other.alpha,
+        )
+    }
+}
+
+impl Mul<f32> for ColourF32 {
+    type Output = Self;
+
+    fn mul(self, val: f32) -> Self {
+        Self::new(
+            self.red * val,
+            self.green * val,
+            self.blue * val,
+            self.alpha * val,
+        )
+    }
+}
+
+impl MulAssign<f32> for ColourF32 {
+    fn mul_assign(&mut self, val: f32) {
+        *self = *self * val;
+    }
+}
+
+impl Div<f32> for ColourF32 {
+    type Output = Self;
+
+    fn div(self, val: f32) -> Self {
+        Self::new(
+            self.red / val,
+            self.green / val,
+            self.blue / val,
+            self.alpha / val,
+        )
+    }
+}
+
+impl DivAssign<f32> for ColourF32 {
+    fn div_assign(&mut self, val: f32) {
+        *self = *self / val;
+    }
+}
+
+#[cfg(test)]
+mod from {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_and_from_every_colour_value() {
+        for n in 0..=255 {
+            let colour = Colour::new_from_u8s(n, 255 - n, n / 2, n);
+
+            assert_eq!(ColourF32::from(colour).to_colour(), colour);
+        }
+    }
+
+    #[test]
+    fn it_should_clamp_when_converting_to_colour() {
+        let colour = ColourF32::new(2.0, -1.0, 0.5, 1.5);
+
+        assert_eq!(Colour::from(colour), Colour::new_from_u8s(255, 0, 128, 255));
+    }
+}
+
+#[cfg(test)]
+mod operators {
+    use super::*;
+
+    #[test]
+    fn it_should_not_clamp() {
+        let colour = ColourF32::from(Colour::WHITE) * 4.0 + ColourF32::from(Colour::RED);
+
+        assert_eq!(colour, ColourF32::new(5.0, 4.0, 4.0, 5.0));
+        assert_eq!(
+            colour / 4.0 - colour,
+            ColourF32::new(-3.75, -3.0, -3.0, -3.75)
+        );
+    }
+
+    #[test]
+    fn it_should_keep_precision() {
+        let mut colour = ColourF32::from(Colour::new_from_u8s(3, 3, 3, 255));
+        colour *= 0.01;
+        colour /= 0.01;
+
+        assert_eq!(colour.to_colour(), Colour::new_from_u8s(3, 3, 3, 255));
+    }
+
+    #[test]
+    fn it_should_combine_colours() {
+        let mut colour = ColourF32::new(0.5, 1.0, 2.0, 1.0);
+        colour *= ColourF32::new(2.0, 0.5, 0.25, 1.0);
+        assert_eq!(colour, ColourF32::new(1.0, 0.5, 0.5, 1.0));
+
+        colour /= ColourF32::new(0.5, 0.5, 0.5, 1.0);
+        assert_eq!(colour, ColourF32::new(2.0, 1.0, 1.0, 1.0));
+
+        colour += ColourF32::new(1.0, 1.0, 1.0, 0.0);
+        colour -= ColourF32::new(0.5, 0.5, 0.5, 0.0);
+        assert_eq!(colour, ColourF32::new(2.5, 1.5, 1.5, 1.0));
+        assert_eq!(
+            colour % ColourF32::new(1.0, 1.0, 1.0, 1.0),
+            ColourF32::new(0.5, 0.5, 0.5, 0.0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod clamp {
+    use super::*;
+
+    #[test]
+    fn it_should_clamp_every_channel() {
+        assert_eq!(
+            ColourF32::new(2.0, -1.0, 0.5, 1.5).clamp(),
+            ColourF32::new(1.0, 0.0, 0.5, 1.0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tone_map_reinhard {
+    use super::*;
+
+    #[test]
+    fn it_should_bring_hdr_into_range() {
+        let colour = ColourF32::new(0.0, 1.0, 3.0, 2.0).tone_map_reinhard();
+
+        assert_eq!(colour, ColourF32::new(0.0, 0.5, 0.75, 2.0));
+    }
+}
+
+#[cfg(test)]
+mod tone_map_aces {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_bring_hdr_into_range() {
+        let colour = ColourF32::new(0.0, 1.0, 100.0, 1.0).tone_map_aces();
+
+        assert_eq!(colour.red, 0.0);
+        assert_approx_eq!(colour.green, 0.8038, 0.0001);
+        assert!(colour.blue > 0.99 && colour.blue <= 1.0);
+        assert_eq!(colour.alpha, 1.0);
+    }
+
+    #[test]
+    fn it_should_keep_order_of_brightness() {
+        let values = [0.1, 0.5, 1.0, 2.0, 8.0];
+        let mapped: Vec<f32> = values
+            .iter()
+            .map(|n| {
+                ColourF32::new(*n, *n, *n, 1.0)
+                    .tone_map_aces()
+                    .max_channel()
+            })
+            .collect();
+
+        assert!(mapped.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
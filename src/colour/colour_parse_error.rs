@@ -1,9 +1,31 @@
 use std::convert::From;
+use std::error::Error;
+use std::fmt;
 use std::num::ParseIntError;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum ColourParseError {
     InvalidFormat,
+
+    /// The string is not a hex code, function, or known colour name.
+    UnknownName,
+
+    /// The function name before the `(` is not supported.
+    UnknownFunction {
+        position: usize,
+    },
+
+    /// Something else was found where the expected token should be.
+    /// The position is the byte offset into the string.
+    UnexpectedToken {
+        position: usize,
+        expected: &'static str,
+    },
+
+    /// The string ended, when more was expected.
+    UnexpectedEnd {
+        expected: &'static str,
+    },
 }
 
 impl From<ParseIntError> for ColourParseError {
@@ -11,3 +33,23 @@ impl From<ParseIntError> for ColourParseError {
         Self::InvalidFormat
     }
 }
+
+impl fmt::Display for ColourParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "invalid colour format"),
+            Self::UnknownName => write!(f, "unknown colour name"),
+            Self::UnknownFunction { position } => {
+                write!(f, "unknown colour function at position {}", position)
+            }
+            Self::UnexpectedToken { position, expected } => {
+                write!(f, "expected {} at position {}", expected, position)
+            }
+            Self::UnexpectedEnd { expected } => {
+                write!(f, "expected {}, but reached the end", expected)
+            }
+        }
+    }
+}
+
+impl Error for ColourParseError {}
@@ -0,0 +1,19 @@
+/// The colour spaces which colours can be mixed within.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColourSpace {
+    /// Mixes the gamma encoded values directly, the same as `Colour::mix`.
+    Srgb,
+
+    /// Mixes the amount of light, which gives brighter blends than `Srgb`.
+    LinearSrgb,
+
+    /// Perceptually even blends, which avoid muddy colours in the middle.
+    Oklab,
+
+    /// Like `Oklab`, but blends around the colour wheel,
+    /// keeping colours saturated.
+    Oklch,
+
+    /// CIELAB, using the D65 white point.
+    Lab,
+}
@@ -0,0 +1,302 @@
+use crate::Colour;
+
+use super::BlendMode;
+
+/// Compositing treats colours as straight (not premultiplied) alpha,
+/// unless stated otherwise.
+/// `self` is always the source, which is drawn on top of the destination.
+impl Colour {
+    /// Returns a copy with the red, green, and blue multiplied by the alpha.
+    pub fn premultiply(self) -> Self {
+        let alpha = self.alpha_f32();
+
+        Self::new_from_f32s_rounded(
+            self.red_f32() * alpha,
+            self.green_f32() * alpha,
+            self.blue_f32() * alpha,
+            alpha,
+        )
+    }
+
+    /// The reverse of `premultiply`.
+    /// Fully transparent colours become transparent black.
+    pub fn unpremultiply(self) -> Self {
+        let alpha = self.alpha_f32();
+        if alpha <= 0.0 {
+            return Self::new_from_rgba(0);
+        }
+
+        Self::new_from_f32s_rounded(
+            self.red_f32() / alpha,
+            self.green_f32() / alpha,
+            self.blue_f32() / alpha,
+            alpha,
+        )
+    }
+
+    /// Draws this colour on top of the destination.
+    pub fn source_over(self, destination: Self) -> Self {
+        let source_alpha = self.alpha_f32();
+        porter_duff(self, destination, 1.0, 1.0 - source_alpha)
+    }
+
+    /// Keeps this colour only where it overlaps the destination.
+    pub fn source_in(self, destination: Self) -> Self {
+        let destination_alpha = destination.alpha_f32();
+        porter_duff(self, destination, destination_alpha, 0.0)
+    }
+
+    /// Keeps this colour only where it does not overlap the destination.
+    pub fn source_out(self, destination: Self) -> Self {
+        let destination_alpha = destination.alpha_f32();
+        porter_duff(self, destination, 1.0 - destination_alpha, 0.0)
+    }
+
+    /// Draws this colour on top of the destination,
+    /// but only where the destination is.
+    pub fn source_atop(self, destination: Self) -> Self {
+        let source_alpha = self.alpha_f32();
+        let destination_alpha = destination.alpha_f32();
+        porter_duff(self, destination, destination_alpha, 1.0 - source_alpha)
+    }
+
+    /// Keeps the parts of each colour which do not overlap.
+    pub fn xor(self, destination: Self) -> Self {
+        let source_alpha = self.alpha_f32();
+        let destination_alpha = destination.alpha_f32();
+        porter_duff(
+            self,
+            destination,
+            1.0 - destination_alpha,
+            1.0 - source_alpha,
+        )
+    }
+
+    /// Blends this colour with the backdrop using the blend mode,
+    /// and then draws the result on top using `source_over`.
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        let backdrop_alpha = backdrop.alpha_f32();
+        let blend = |backdrop: f32, source: f32| {
+            (1.0 - backdrop_alpha) * source + backdrop_alpha * mode.apply(backdrop, source)
+        };
+
+        let source = Self::new_from_f32s_rounded(
+            blend(backdrop.red_f32(), self.red_f32()),
+            blend(backdrop.green_f32(), self.green_f32()),
+            blend(backdrop.blue_f32(), self.blue_f32()),
+            self.alpha_f32(),
+        );
+
+        source.source_over(backdrop)
+    }
+}
+
+/// The general Porter-Duff operator.
+/// The factors are how much of the source and destination to keep.
+fn porter_duff(
+    source: Colour,
+    destination: Colour,
+    source_factor: f32,
+    destination_factor: f32,
+) -> Colour {
+    let source_alpha = source.alpha_f32() * source_factor;
+    let destination_alpha = destination.alpha_f32() * destination_factor;
+    let alpha = source_alpha + destination_alpha;
+
+    if alpha <= 0.0 {
+        return Colour::new_from_rgba(0);
+    }
+
+    let composite = |source: f32, destination: f32| {
+        (source * source_alpha + destination * destination_alpha) / alpha
+    };
+
+    Colour::new_from_f32s_rounded(
+        composite(source.red_f32(), destination.red_f32()),
+        composite(source.green_f32(), destination.green_f32()),
+        composite(source.blue_f32(), destination.blue_f32()),
+        alpha,
+    )
+}
+
+#[cfg(test)]
+mod premultiply {
+    use super::*;
+
+    #[test]
+    fn it_should_multiply_by_alpha() {
+        assert_eq!(
+            Colour::new_from_u8s(255, 128, 0, 128).premultiply(),
+            Colour::new_from_u8s(128, 64, 0, 128)
+        );
+        assert_eq!(Colour::RED.premultiply(), Colour::RED);
+    }
+}
+
+#[cfg(test)]
+mod unpremultiply {
+    use super::*;
+
+    #[test]
+    fn it_should_reverse_premultiply() {
+        let colour = Colour::new_from_u8s(255, 128, 0, 128);
+
+        assert_eq!(colour.premultiply().unpremultiply(), colour);
+    }
+
+    #[test]
+    fn it_should_make_transparent_black() {
+        assert_eq!(
+            Colour::new_from_u8s(10, 20, 30, 0).unpremultiply(),
+            Colour::new_from_rgba(0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod source_over {
+    use super::*;
+
+    #[test]
+    fn it_should_replace_with_opaque_colour() {
+        assert_eq!(Colour::RED.source_over(Colour::BLUE), Colour::RED);
+    }
+
+    #[test]
+    fn it_should_combine_alpha() {
+        let colour = Colour::BLUE
+            .replace_alpha_u32(128)
+            .source_over(Colour::RED.replace_alpha_u32(128));
+
+        assert_eq!(colour, Colour::new_from_u8s(85, 0, 170, 192));
+    }
+
+    #[test]
+    fn it_should_keep_destination_under_transparent_colour() {
+        let destination = Colour::new_from_u8s(10, 20, 30, 40);
+
+        assert_eq!(
+            Colour::WHITE.replace_alpha_u32(0).source_over(destination),
+            destination
+        );
+    }
+}
+
+#[cfg(test)]
+mod source_in {
+    use super::*;
+
+    #[test]
+    fn it_should_take_alpha_from_destination() {
+        let destination = Colour::BLUE.replace_alpha_u32(51);
+
+        assert_eq!(
+            Colour::RED.source_in(destination),
+            Colour::RED.replace_alpha_u32(51)
+        );
+        assert_eq!(
+            Colour::RED.source_in(Colour::new_from_rgba(0)),
+            Colour::new_from_rgba(0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod source_out {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_source_outside_destination() {
+        let destination = Colour::BLUE.replace_alpha_u32(51);
+
+        assert_eq!(
+            Colour::RED.source_out(destination),
+            Colour::RED.replace_alpha_u32(204)
+        );
+        assert_eq!(
+            Colour::RED.source_out(Colour::BLUE),
+            Colour::new_from_rgba(0)
+        );
+    }
+}
+
+#[cfg(test)]
+mod source_atop {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_destination_alpha() {
+        let destination = Colour::BLUE.replace_alpha_u32(51);
+
+        assert_eq!(
+            Colour::RED.source_atop(destination),
+            Colour::RED.replace_alpha_u32(51)
+        );
+        assert_eq!(
+            Colour::RED.replace_alpha_u32(0).source_atop(Colour::BLUE),
+            Colour::BLUE
+        );
+    }
+}
+
+#[cfg(test)]
+mod xor {
+    use super::*;
+
+    #[test]
+    fn it_should_remove_overlap() {
+        assert_eq!(Colour::RED.xor(Colour::BLUE), Colour::new_from_rgba(0));
+        assert_eq!(Colour::RED.xor(Colour::new_from_rgba(0)), Colour::RED);
+    }
+
+    #[test]
+    fn it_should_keep_both_when_translucent() {
+        let colour = Colour::RED
+            .replace_alpha_u32(128)
+            .xor(Colour::BLUE.replace_alpha_u32(128));
+
+        assert_eq!(colour, Colour::new_from_u8s(128, 0, 128, 127));
+    }
+}
+
+#[cfg(test)]
+mod blend {
+    use super::*;
+
+    #[test]
+    fn it_should_blend_opaque_colours() {
+        let backdrop = Colour::new_from_u8s(255, 128, 0, 255);
+
+        assert_eq!(
+            Colour::GREY.blend(backdrop, BlendMode::Multiply),
+            Colour::new_from_u8s(128, 64, 0, 255)
+        );
+        assert_eq!(
+            Colour::WHITE.blend(backdrop, BlendMode::Difference),
+            Colour::new_from_u8s(0, 127, 255, 255)
+        );
+        assert_eq!(
+            Colour::GREY.blend(backdrop, BlendMode::Normal),
+            Colour::GREY
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_mode_over_transparent_backdrop() {
+        let source = Colour::GREY.replace_alpha_u32(128);
+
+        assert_eq!(
+            source.blend(Colour::new_from_rgba(0), BlendMode::Multiply),
+            source
+        );
+    }
+
+    #[test]
+    fn it_should_mix_with_translucent_source() {
+        let colour = Colour::BLACK
+            .replace_alpha_u32(0)
+            .blend(Colour::WHITE, BlendMode::Multiply);
+
+        assert_eq!(colour, Colour::WHITE);
+    }
+}
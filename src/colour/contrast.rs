@@ -0,0 +1,194 @@
+use crate::Colour;
+
+use super::linear_rgba::srgb_to_linear;
+use super::Oklch;
+use super::TextSize;
+use super::WcagLevel;
+
+/// How many steps to take when searching for a lightness.
+const ADJUST_STEPS: usize = 24;
+
+impl Colour {
+    /// The relative luminance, as defined by WCAG.
+    /// This is from 0.0 for black, to 1.0 for white.
+    ///
+    /// Alpha is ignored.
+    pub fn relative_luminance(self) -> f32 {
+        0.2126 * srgb_to_linear(self.red_f32())
+            + 0.7152 * srgb_to_linear(self.green_f32())
+            + 0.0722 * srgb_to_linear(self.blue_f32())
+    }
+
+    /// The WCAG contrast ratio between two colours.
+    /// This is from 1.0 for the same colour, to 21.0 for black and white.
+    ///
+    /// The order of the colours does not matter.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns true if this colour, as text on the background, meets the WCAG level given.
+    pub fn meets_wcag(self, background: Self, level: WcagLevel, text_size: TextSize) -> bool {
+        self.contrast_ratio(background) >= level.min_contrast_ratio(text_size)
+    }
+
+    /// Returns a copy with the lightness changed as little as possible,
+    /// so it has at least the contrast ratio given against the background.
+    ///
+    /// The lightness is moved within Oklch, keeping the hue.
+    /// If the ratio cannot be reached, the lightest or darkest
+    /// version with the most contrast is returned.
+    pub fn adjust_for_contrast(self, background: Self, target_ratio: f32) -> Self {
+        if self.contrast_ratio(background) >= target_ratio {
+            return self;
+        }
+
+        let oklch = self.to_oklch();
+        let with_lightness = |lightness: f32| {
+            Oklch::new(lightness, oklch.chroma, oklch.hue, oklch.alpha).to_colour()
+        };
+
+        let lighter = find_lightness(oklch.lightness, 1.0, |lightness| {
+            with_lightness(lightness).contrast_ratio(background) >= target_ratio
+        });
+        let darker = find_lightness(oklch.lightness, 0.0, |lightness| {
+            with_lightness(lightness).contrast_ratio(background) >= target_ratio
+        });
+
+        let lightness = match (lighter, darker) {
+            (Some(lighter), Some(darker)) => {
+                if lighter - oklch.lightness < oklch.lightness - darker {
+                    lighter
+                } else {
+                    darker
+                }
+            }
+            (Some(lighter), None) => lighter,
+            (None, Some(darker)) => darker,
+            (None, None) => {
+                let (lightest, darkest) = (with_lightness(1.0), with_lightness(0.0));
+                if lightest.contrast_ratio(background) > darkest.contrast_ratio(background) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        };
+
+        with_lightness(lightness)
+    }
+}
+
+/// Searches from the start towards the end for the closest lightness which passes.
+/// Returns None if even the end does not pass.
+fn find_lightness<F>(start: f32, end: f32, passes: F) -> Option<f32>
+where
+    F: Fn(f32) -> bool,
+{
+    if !passes(end) {
+        return None;
+    }
+
+    let (mut failing, mut passing) = (start, end);
+    for _ in 0..ADJUST_STEPS {
+        let middle = (failing + passing) / 2.0;
+        if passes(middle) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+
+    Some(passing)
+}
+
+#[cfg(test)]
+mod relative_luminance {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_match_reference_values() {
+        assert_eq!(Colour::BLACK.relative_luminance(), 0.0);
+        assert_approx_eq!(Colour::WHITE.relative_luminance(), 1.0);
+        assert_approx_eq!(Colour::RED.relative_luminance(), 0.2126);
+        assert_approx_eq!(Colour::GREY.relative_luminance(), 0.2159, 0.0001);
+    }
+}
+
+#[cfg(test)]
+mod contrast_ratio {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_match_reference_values() {
+        assert_approx_eq!(Colour::BLACK.contrast_ratio(Colour::WHITE), 21.0, 0.0001);
+        assert_approx_eq!(Colour::WHITE.contrast_ratio(Colour::BLACK), 21.0, 0.0001);
+        assert_approx_eq!(Colour::RED.contrast_ratio(Colour::RED), 1.0);
+        assert_approx_eq!(
+            Colour::new_from_rgba(0x777777ff).contrast_ratio(Colour::WHITE),
+            4.48,
+            0.01
+        );
+    }
+}
+
+#[cfg(test)]
+mod meets_wcag {
+    use super::*;
+
+    #[test]
+    fn it_should_check_level_and_text_size() {
+        let grey = Colour::new_from_rgba(0x777777ff);
+
+        assert!(!grey.meets_wcag(Colour::WHITE, WcagLevel::AA, TextSize::Normal));
+        assert!(grey.meets_wcag(Colour::WHITE, WcagLevel::AA, TextSize::Large));
+        assert!(!grey.meets_wcag(Colour::WHITE, WcagLevel::AAA, TextSize::Large));
+        assert!(Colour::BLACK.meets_wcag(Colour::WHITE, WcagLevel::AAA, TextSize::Normal));
+    }
+}
+
+#[cfg(test)]
+mod adjust_for_contrast {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_colours_which_already_pass() {
+        assert_eq!(
+            Colour::BLACK.adjust_for_contrast(Colour::WHITE, 7.0),
+            Colour::BLACK
+        );
+    }
+
+    #[test]
+    fn it_should_darken_against_light_backgrounds() {
+        let colour = Colour::LIGHT_BLUE.adjust_for_contrast(Colour::WHITE, 4.5);
+        let ratio = colour.contrast_ratio(Colour::WHITE);
+
+        assert!(ratio >= 4.5, "{}", ratio);
+        assert!(ratio < 4.7, "{}", ratio);
+        assert!(colour.relative_luminance() < Colour::LIGHT_BLUE.relative_luminance());
+    }
+
+    #[test]
+    fn it_should_lighten_against_dark_backgrounds() {
+        let colour = Colour::DARK_RED.adjust_for_contrast(Colour::BLACK, 7.0);
+        let ratio = colour.contrast_ratio(Colour::BLACK);
+
+        assert!(ratio >= 7.0, "{}", ratio);
+        assert!(ratio < 7.3, "{}", ratio);
+        assert!(colour.red_u8() > colour.blue_u8());
+    }
+
+    #[test]
+    fn it_should_use_most_contrast_when_target_is_impossible() {
+        assert_eq!(
+            Colour::DARK_GREY.adjust_for_contrast(Colour::GREY, 21.0),
+            Colour::BLACK
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Colour;
+
+use super::ColourParseError;
+
+mod css_named_colours;
+
+mod css_parser;
+use self::css_parser::CssParser;
+
+impl Colour {
+    /// Returns the colour as a lowercase hex code, such as `#ff8000`.
+    /// The alpha is only included when it is not fully opaque, such as `#ff800080`.
+    pub fn to_hex_string(self) -> String {
+        if self.alpha_u8() == 255 {
+            format!("#{:06x}", self.to_rgba_u32() >> 8)
+        } else {
+            format!("#{:08x}", self.to_rgba_u32())
+        }
+    }
+
+    /// Returns the colour using the CSS `rgb()` function, such as `rgb(255, 128, 0)`.
+    /// When it is not fully opaque, `rgba()` is used instead, such as `rgba(255, 128, 0, 0.5)`.
+    pub fn to_css_string(self) -> String {
+        let (red, green, blue) = (self.red_u8(), self.green_u8(), self.blue_u8());
+        if self.alpha_u8() == 255 {
+            return format!("rgb({}, {}, {})", red, green, blue);
+        }
+
+        // Three decimal places is enough to parse back to the same alpha.
+        let alpha = format!("{:.3}", self.alpha_f32());
+        let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+
+        format!("rgba({}, {}, {}, {})", red, green, blue, alpha)
+    }
+}
+
+/// Parses any CSS colour. This supports ...
+///  * Hex codes, such as `#ff8000`, using `Colour::from_hex_str`.
+///  * `rgb()` and `rgba()`, with numbers or percentages.
+///  * `hsl()` and `hsla()`.
+///  * `hwb()`.
+///  * The 148 named colours, and `transparent`.
+///
+/// Functions can use the older comma syntax, `rgba(255, 0, 0, 0.5)`,
+/// or the newer space syntax, `rgb(255 0 0 / 50%)`.
+impl FromStr for Colour {
+    type Err = ColourParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('#') {
+            return Colour::from_hex_str(trimmed);
+        }
+
+        CssParser::new(s).parse()
+    }
+}
+
+/// Writes the colour as a hex code, the same as `Colour::to_hex_string`.
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Colour, ColourParseError> {
+        s.parse()
+    }
+
+    #[test]
+    fn it_should_parse_hex_codes() {
+        assert_eq!(parse("#ff8000"), Ok(Colour::new_from_rgba(0xff8000ff)));
+        assert_eq!(parse("  #f80  "), Ok(Colour::new_from_rgba(0xff8800ff)));
+        assert_eq!(parse("0xff800080"), Ok(Colour::new_from_rgba(0xff800080)));
+        assert_eq!(parse("#ggg"), Err(ColourParseError::InvalidFormat));
+    }
+
+    #[test]
+    fn it_should_parse_named_colours() {
+        assert_eq!(
+            parse("rebeccapurple"),
+            Ok(Colour::new_from_rgba(0x663399ff))
+        );
+        assert_eq!(parse(" AliceBlue "), Ok(Colour::new_from_rgba(0xf0f8ffff)));
+        assert_eq!(parse("transparent"), Ok(Colour::new_from_rgba(0)));
+        assert_eq!(parse("notacolour"), Err(ColourParseError::UnknownName));
+    }
+
+    #[test]
+    fn it_should_parse_rgb() {
+        let expected = Ok(Colour::new_from_rgba(0xff8000ff));
+
+        assert_eq!(parse("rgb(255, 128, 0)"), expected);
+        assert_eq!(parse("RGB(255 128 0)"), expected);
+        assert_eq!(parse("rgb(100%, 50.2%, 0%)"), expected);
+        assert_eq!(parse("rgb(300, 128, -10)"), expected);
+    }
+
+    #[test]
+    fn it_should_parse_exponents() {
+        assert_eq!(parse("rgb(1e2, 0, 0)"), parse("rgb(100, 0, 0)"));
+        assert_eq!(parse("rgb(2.55E+2 0 0)"), Ok(Colour::RED));
+        assert_eq!(parse("rgb(0 0 0 / 5e-1)"), parse("rgb(0 0 0 / 0.5)"));
+        assert_eq!(parse("hsl(1.2e2deg 100% 50%)"), Ok(Colour::GREEN));
+    }
+
+    #[test]
+    fn it_should_parse_rgb_with_alpha() {
+        let expected = Ok(Colour::new_from_rgba(0xff800080));
+
+        assert_eq!(parse("rgba(255, 128, 0, 0.5)"), expected);
+        assert_eq!(parse("rgb(255 128 0 / 50%)"), expected);
+        assert_eq!(parse("rgba(255 128 0 / .5)"), expected);
+    }
+
+    #[test]
+    fn it_should_parse_hsl() {
+        assert_eq!(parse("hsl(0, 100%, 50%)"), Ok(Colour::RED));
+        assert_eq!(parse("hsl(120deg 100% 50%)"), Ok(Colour::GREEN));
+        assert_eq!(parse("hsl(0.6667turn 100% 50%)"), Ok(Colour::BLUE));
+        assert_eq!(
+            parse("hsla(-240, 100%, 50%, 0.5)"),
+            Ok(Colour::GREEN.replace_alpha_u32(128))
+        );
+        assert_eq!(parse("hsl(0 0% 100%)"), Ok(Colour::WHITE));
+    }
+
+    #[test]
+    fn it_should_parse_hwb() {
+        assert_eq!(parse("hwb(0 0% 0%)"), Ok(Colour::RED));
+        assert_eq!(
+            parse("hwb(240 0% 50%)"),
+            Ok(Colour::new_from_rgba(0x000080ff))
+        );
+        assert_eq!(
+            parse("hwb(90 60% 60%)"),
+            Ok(Colour::new_from_rgba(0x808080ff))
+        );
+        assert_eq!(
+            parse("hwb(0 20% 20% / 0)"),
+            Ok(Colour::new_from_rgba(0xcc333300))
+        );
+    }
+
+    #[test]
+    fn it_should_report_unknown_functions() {
+        assert_eq!(
+            parse("  lab(50 0 0)"),
+            Err(ColourParseError::UnknownFunction { position: 2 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unexpected_tokens() {
+        assert_eq!(
+            parse("rgb(255, x, 0)"),
+            Err(ColourParseError::UnexpectedToken {
+                position: 9,
+                expected: "number"
+            })
+        );
+        assert_eq!(
+            parse("rgb(255, 0 0)"),
+            Err(ColourParseError::UnexpectedToken {
+                position: 11,
+                expected: "','"
+            })
+        );
+        assert_eq!(
+            parse("hsl(10%, 0%, 0%)"),
+            Err(ColourParseError::UnexpectedToken {
+                position: 4,
+                expected: "hue"
+            })
+        );
+        assert_eq!(
+            parse("rgb(1 2 3) red"),
+            Err(ColourParseError::UnexpectedToken {
+                position: 11,
+                expected: "end of colour"
+            })
+        );
+        assert_eq!(
+            parse("rgb(1 2 3px)"),
+            Err(ColourParseError::UnexpectedToken {
+                position: 9,
+                expected: "unit"
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unexpected_end() {
+        assert_eq!(
+            parse("rgb(1 2 3"),
+            Err(ColourParseError::UnexpectedEnd { expected: "')'" })
+        );
+        assert_eq!(
+            parse("rgb(1, 2,"),
+            Err(ColourParseError::UnexpectedEnd { expected: "number" })
+        );
+        assert_eq!(
+            parse(""),
+            Err(ColourParseError::UnexpectedEnd { expected: "colour" })
+        );
+        assert_eq!(
+            parse("  \t"),
+            Err(ColourParseError::UnexpectedEnd { expected: "colour" })
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_hex_string {
+    use super::*;
+
+    #[test]
+    fn it_should_skip_alpha_when_opaque() {
+        assert_eq!(Colour::new_from_rgba(0xff8000ff).to_hex_string(), "#ff8000");
+        assert_eq!(
+            Colour::new_from_rgba(0xff800080).to_hex_string(),
+            "#ff800080"
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip() {
+        let colour = Colour::new_from_rgba(0x12345678);
+
+        assert_eq!(colour.to_hex_string().parse(), Ok(colour));
+        assert_eq!(colour.to_string(), colour.to_hex_string());
+    }
+}
+
+#[cfg(test)]
+mod to_css_string {
+    use super::*;
+
+    #[test]
+    fn it_should_format_as_rgb() {
+        assert_eq!(
+            Colour::new_from_rgba(0xff8000ff).to_css_string(),
+            "rgb(255, 128, 0)"
+        );
+        assert_eq!(
+            Colour::new_from_rgba(0xff800000).to_css_string(),
+            "rgba(255, 128, 0, 0)"
+        );
+        assert_eq!(
+            Colour::new_from_rgba(0xff800033).to_css_string(),
+            "rgba(255, 128, 0, 0.2)"
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_every_alpha() {
+        for alpha in 0..=255 {
+            let colour = Colour::new_from_u8s(1, 2, 3, alpha);
+
+            assert_eq!(colour.to_css_string().parse(), Ok(colour));
+        }
+    }
+}
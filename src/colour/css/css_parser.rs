@@ -0,0 +1,319 @@
+use std::f32::consts::TAU;
+
+use crate::colour::Hsla;
+use crate::colour::Hsva;
+use crate::Colour;
+
+use super::css_named_colours::CSS_NAMED_COLOURS;
+use super::ColourParseError;
+
+/// The unit written after a number.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CssUnit {
+    None,
+    Percent,
+    Degrees,
+    Radians,
+    Gradians,
+    Turns,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct CssValue {
+    value: f32,
+    unit: CssUnit,
+    position: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CssFunction {
+    Rgb,
+    Hsl,
+    Hwb,
+}
+
+/// Parses a CSS colour, keeping track of the position for errors.
+pub(crate) struct CssParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> CssParser<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    pub(crate) fn parse(mut self) -> Result<Colour, ColourParseError> {
+        self.skip_whitespace();
+        let name_position = self.position;
+        let name = self.next_identifier();
+
+        if self.peek() == Some('(') {
+            self.position += 1;
+            let function = match name.to_ascii_lowercase().as_str() {
+                "rgb" | "rgba" => CssFunction::Rgb,
+                "hsl" | "hsla" => CssFunction::Hsl,
+                "hwb" => CssFunction::Hwb,
+                _ => {
+                    return Err(ColourParseError::UnknownFunction {
+                        position: name_position,
+                    })
+                }
+            };
+
+            let colour = self.parse_function(function)?;
+            self.expect_end()?;
+            return Ok(colour);
+        }
+
+        self.skip_whitespace();
+        if !name.is_empty() && self.peek().is_none() {
+            if let Some(colour) = find_named_colour(name) {
+                return Ok(colour);
+            }
+        }
+
+        // Hex codes without a `#`, such as `0x123` or `ff0000`.
+        Colour::from_hex_str(self.input.trim()).map_err(|_| {
+            if name.is_empty() {
+                self.unexpected_at(name_position, "colour")
+            } else {
+                ColourParseError::UnknownName
+            }
+        })
+    }
+
+    fn parse_function(&mut self, function: CssFunction) -> Result<Colour, ColourParseError> {
+        let first = self.next_value()?;
+
+        // Older CSS separates values with commas, and newer CSS uses spaces.
+        self.skip_whitespace();
+        let is_comma_separated = self.peek() == Some(',');
+        if is_comma_separated {
+            self.expect_char(',', "','")?;
+        }
+
+        let second = self.next_value()?;
+        if is_comma_separated {
+            self.expect_char(',', "','")?;
+        }
+
+        let third = self.next_value()?;
+
+        self.skip_whitespace();
+        let alpha_separator = if is_comma_separated { ',' } else { '/' };
+        let alpha = if self.peek() == Some(alpha_separator) {
+            self.position += 1;
+            to_alpha(self.next_value()?)?
+        } else {
+            1.0
+        };
+
+        self.expect_char(')', "')'")?;
+
+        match function {
+            CssFunction::Rgb => Ok(Colour::new_from_f32s_rounded(
+                to_rgb_channel(first)?,
+                to_rgb_channel(second)?,
+                to_rgb_channel(third)?,
+                alpha,
+            )),
+            CssFunction::Hsl => Ok(Hsla::new(
+                to_hue(first)?,
+                to_percentage(second)?,
+                to_percentage(third)?,
+                alpha,
+            )
+            .to_colour()),
+            CssFunction::Hwb => Ok(hwb_to_colour(
+                to_hue(first)?,
+                to_percentage(second)?,
+                to_percentage(third)?,
+                alpha,
+            )),
+        }
+    }
+
+    /// Reads a number, and the unit after it.
+    fn next_value(&mut self) -> Result<CssValue, ColourParseError> {
+        self.skip_whitespace();
+        let position = self.position;
+
+        if matches!(self.peek(), Some('+' | '-')) {
+            self.position += 1;
+        }
+        self.skip_while(|c| c.is_ascii_digit() || c == '.');
+        self.skip_exponent();
+
+        let value = self.input[position..self.position]
+            .parse::<f32>()
+            .map_err(|_| self.unexpected_at(position, "number"))?;
+
+        let unit_position = self.position;
+        let unit = if self.peek() == Some('%') {
+            self.position += 1;
+            CssUnit::Percent
+        } else {
+            match self.next_identifier().to_ascii_lowercase().as_str() {
+                "" => CssUnit::None,
+                "deg" => CssUnit::Degrees,
+                "rad" => CssUnit::Radians,
+                "grad" => CssUnit::Gradians,
+                "turn" => CssUnit::Turns,
+                _ => return Err(self.unexpected_at(unit_position, "unit")),
+            }
+        };
+
+        Ok(CssValue {
+            value,
+            unit,
+            position,
+        })
+    }
+
+    /// Skips an exponent such as `e2` or `E-3`, if there is one.
+    ///
+    /// The `e` is only taken when digits follow, so it isn't mistaken for a unit.
+    fn skip_exponent(&mut self) {
+        let rest = &self.input.as_bytes()[self.position..];
+        if !matches!(rest.first(), Some(b'e' | b'E')) {
+            return;
+        }
+
+        let sign_len = usize::from(matches!(rest.get(1), Some(b'+' | b'-')));
+        if rest.get(1 + sign_len).is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1 + sign_len;
+            self.skip_while(|c| c.is_ascii_digit());
+        }
+    }
+
+    fn next_identifier(&mut self) -> &'a str {
+        let start = self.position;
+        self.skip_while(|c| c.is_ascii_alphabetic() || c == '-');
+
+        &self.input[start..self.position]
+    }
+
+    fn expect_char(
+        &mut self,
+        expected_char: char,
+        expected: &'static str,
+    ) -> Result<(), ColourParseError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected_char) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected_at(self.position, expected))
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), ColourParseError> {
+        self.skip_whitespace();
+
+        if self.peek().is_none() {
+            Ok(())
+        } else {
+            Err(self.unexpected_at(self.position, "end of colour"))
+        }
+    }
+
+    fn unexpected_at(&self, position: usize, expected: &'static str) -> ColourParseError {
+        if position >= self.input.len() {
+            ColourParseError::UnexpectedEnd { expected }
+        } else {
+            ColourParseError::UnexpectedToken { position, expected }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.skip_while(|c| c.is_whitespace());
+    }
+
+    fn skip_while<F>(&mut self, predicate: F)
+    where
+        F: Fn(char) -> bool,
+    {
+        while let Some(c) = self.peek().filter(|c| predicate(*c)) {
+            self.position += c.len_utf8();
+        }
+    }
+}
+
+fn find_named_colour(name: &str) -> Option<Colour> {
+    let name = name.to_ascii_lowercase();
+    if name == "transparent" {
+        return Some(Colour::new_from_rgba(0));
+    }
+
+    CSS_NAMED_COLOURS
+        .binary_search_by(|(colour_name, _)| (*colour_name).cmp(name.as_str()))
+        .ok()
+        .map(|index| Colour::new_from_rgba(CSS_NAMED_COLOURS[index].1))
+}
+
+fn to_rgb_channel(value: CssValue) -> Result<f32, ColourParseError> {
+    match value.unit {
+        CssUnit::None => Ok(value.value / 255.0),
+        CssUnit::Percent => Ok(value.value / 100.0),
+        _ => Err(ColourParseError::UnexpectedToken {
+            position: value.position,
+            expected: "number or percentage",
+        }),
+    }
+}
+
+fn to_alpha(value: CssValue) -> Result<f32, ColourParseError> {
+    match value.unit {
+        CssUnit::None => Ok(value.value),
+        CssUnit::Percent => Ok(value.value / 100.0),
+        _ => Err(ColourParseError::UnexpectedToken {
+            position: value.position,
+            expected: "number or percentage",
+        }),
+    }
+}
+
+/// Numbers without a unit are treated as a percentage, as newer CSS allows.
+fn to_percentage(value: CssValue) -> Result<f32, ColourParseError> {
+    match value.unit {
+        CssUnit::None | CssUnit::Percent => Ok((value.value / 100.0).clamp(0.0, 1.0)),
+        _ => Err(ColourParseError::UnexpectedToken {
+            position: value.position,
+            expected: "percentage",
+        }),
+    }
+}
+
+/// Returns the hue as a fraction of a full turn.
+fn to_hue(value: CssValue) -> Result<f32, ColourParseError> {
+    let turns = match value.unit {
+        CssUnit::None | CssUnit::Degrees => value.value / 360.0,
+        CssUnit::Radians => value.value / TAU,
+        CssUnit::Gradians => value.value / 400.0,
+        CssUnit::Turns => value.value,
+        CssUnit::Percent => {
+            return Err(ColourParseError::UnexpectedToken {
+                position: value.position,
+                expected: "hue",
+            })
+        }
+    };
+
+    Ok(turns.rem_euclid(1.0))
+}
+
+fn hwb_to_colour(hue: f32, whiteness: f32, blackness: f32, alpha: f32) -> Colour {
+    if whiteness + blackness >= 1.0 {
+        let grey = whiteness / (whiteness + blackness);
+        return Colour::new_from_f32s_rounded(grey, grey, grey, alpha);
+    }
+
+    let value = 1.0 - blackness;
+    Hsva::new(hue, 1.0 - whiteness / value, value, alpha).to_colour()
+}
@@ -0,0 +1,445 @@
+use crate::num::NumberExtensions;
+use crate::Colour;
+
+use super::ColourSpace;
+
+mod easing;
+pub use self::easing::*;
+
+mod gradient_samples_iterator;
+pub use self::gradient_samples_iterator::*;
+
+mod gradient_spread;
+pub use self::gradient_spread::*;
+
+/// A series of colours, placed at positions along a line,
+/// which are blended between.
+///
+/// Stops are usually placed from 0.0 to 1.0, and are always kept sorted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Colour)>,
+
+    /// The easing for each segment, between one stop and the next.
+    /// There is one more stop than there are segments.
+    easings: Vec<Easing>,
+
+    space: ColourSpace,
+    spread: GradientSpread,
+}
+
+impl Gradient {
+    /// Creates a gradient from the stops given, which do not need to be in order.
+    ///
+    /// It mixes in sRGB, with linear easing, and clamps at the ends.
+    pub fn new(mut stops: Vec<(f32, Colour)>) -> Self {
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self {
+            easings: vec![Easing::default(); stops.len().saturating_sub(1)],
+            stops,
+            space: ColourSpace::Srgb,
+            spread: GradientSpread::default(),
+        }
+    }
+
+    /// Creates a gradient with the colours spaced evenly from 0.0 to 1.0.
+    pub fn new_from_colours(colours: &[Colour]) -> Self {
+        let last_index = colours.len().saturating_sub(1).max(1) as f32;
+
+        Self::new(
+            colours
+                .iter()
+                .enumerate()
+                .map(|(i, colour)| (i as f32 / last_index, *colour))
+                .collect(),
+        )
+    }
+
+    /// Sets the colour space used to blend between stops.
+    pub fn with_space(mut self, space: ColourSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Sets what happens when sampled beyond the first or last stop.
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Sets the easing used between every stop.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easings.fill(easing);
+        self
+    }
+
+    /// Sets the easing between one stop and the next,
+    /// where index 0 is the segment from the first stop to the second.
+    ///
+    /// Panics if the segment does not exist.
+    pub fn with_segment_easing(mut self, index: usize, easing: Easing) -> Self {
+        self.easings[index] = easing;
+        self
+    }
+
+    /// Adds a new stop, keeping the stops in order.
+    ///
+    /// If it splits a segment, both halves keep that segment's easing.
+    pub fn add_stop(&mut self, position: f32, colour: Colour) {
+        let index = self
+            .stops
+            .partition_point(|(stop_position, _)| *stop_position <= position);
+
+        if !self.stops.is_empty() {
+            let split_index = index.saturating_sub(1).min(self.easings.len());
+            let easing = self.easings.get(split_index).copied().unwrap_or_default();
+            self.easings.insert(split_index, easing);
+        }
+
+        self.stops.insert(index, (position, colour));
+    }
+
+    pub fn stops(&self) -> &[(f32, Colour)] {
+        &self.stops
+    }
+
+    pub fn space(&self) -> ColourSpace {
+        self.space
+    }
+
+    pub fn spread(&self) -> GradientSpread {
+        self.spread
+    }
+
+    /// Returns the colour at the position given.
+    ///
+    /// An empty gradient is transparent black.
+    pub fn sample(&self, position: f32) -> Colour {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Colour::new_from_rgba(0);
+        };
+
+        // NaN, or infinity repeated or reflected, has no place on the gradient.
+        let position = self.spread_position(position, first.0, last.0);
+        if position.is_nan() || position <= first.0 {
+            return first.1;
+        }
+        if position >= last.0 {
+            return last.1;
+        }
+
+        // The first stop after the position. This always exists, as it is before the last stop.
+        let end_index = self
+            .stops
+            .partition_point(|(stop_position, _)| *stop_position <= position);
+        let (start_position, start_colour) = self.stops[end_index - 1];
+        let (end_position, end_colour) = self.stops[end_index];
+
+        let amount = position.scale_percentage(start_position..end_position);
+        let amount = self.easings[end_index - 1].apply(amount);
+
+        mix_rounded(self.space, start_colour, end_colour, amount)
+    }
+
+    /// Returns evenly spaced colours from 0.0 to 1.0 inclusive.
+    /// This is useful for building a lookup table.
+    pub fn iter_samples(&self, num_samples: usize) -> GradientSamplesIterator<'_> {
+        GradientSamplesIterator::new(self, num_samples)
+    }
+
+    /// Moves the position to be between the start and end, based on the spread.
+    fn spread_position(&self, position: f32, start: f32, end: f32) -> f32 {
+        let length = end - start;
+        if length <= 0.0 {
+            return position;
+        }
+
+        match self.spread {
+            GradientSpread::Clamp => position,
+            GradientSpread::Repeat => {
+                let offset = (position - start).rem_euclid(length);
+
+                // Landing exactly on the end of a repeat should give the end colour.
+                if offset == 0.0 && position > start {
+                    end
+                } else {
+                    start + offset
+                }
+            }
+            GradientSpread::Reflect => {
+                let offset = (position - start).rem_euclid(length * 2.0);
+
+                if offset > length {
+                    start + length * 2.0 - offset
+                } else {
+                    start + offset
+                }
+            }
+        }
+    }
+}
+
+/// Mixes the same as `Colour::mix_in`, except sRGB is rounded rather than truncated.
+/// This keeps a segment between two of the same colour as that colour.
+fn mix_rounded(space: ColourSpace, from: Colour, to: Colour, amount: f32) -> Colour {
+    if space != ColourSpace::Srgb {
+        return from.mix_in(space, to, amount);
+    }
+
+    let mix = |from: f32, to: f32| from + (to - from) * amount;
+    Colour::new_from_f32s_rounded(
+        mix(from.red_f32(), to.red_f32()),
+        mix(from.green_f32(), to.green_f32()),
+        mix(from.blue_f32(), to.blue_f32()),
+        mix(from.alpha_f32(), to.alpha_f32()),
+    )
+}
+
+#[cfg(test)]
+fn new_test_gradient() -> Gradient {
+    Gradient::new(vec![
+        (1.0, Colour::BLUE),
+        (0.0, Colour::BLACK),
+        (0.5, Colour::WHITE),
+    ])
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_should_sort_stops() {
+        let gradient = new_test_gradient();
+
+        assert_eq!(
+            gradient.stops(),
+            &[
+                (0.0, Colour::BLACK),
+                (0.5, Colour::WHITE),
+                (1.0, Colour::BLUE)
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod new_from_colours {
+    use super::*;
+
+    #[test]
+    fn it_should_space_colours_evenly() {
+        let gradient = Gradient::new_from_colours(&[Colour::RED, Colour::GREEN, Colour::BLUE]);
+
+        assert_eq!(
+            gradient.stops(),
+            &[
+                (0.0, Colour::RED),
+                (0.5, Colour::GREEN),
+                (1.0, Colour::BLUE)
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod add_stop {
+    use super::*;
+
+    #[test]
+    fn it_should_insert_in_order() {
+        let mut gradient = new_test_gradient();
+        gradient.add_stop(0.25, Colour::RED);
+
+        assert_eq!(gradient.stops()[1], (0.25, Colour::RED));
+        assert_eq!(gradient.sample(0.25), Colour::RED);
+    }
+
+    #[test]
+    fn it_should_keep_easing_of_split_segment() {
+        let mut gradient = new_test_gradient().with_segment_easing(1, Easing::Step);
+        gradient.add_stop(0.75, Colour::RED);
+
+        assert_eq!(gradient.sample(0.6), Colour::WHITE);
+        assert_eq!(gradient.sample(0.9), Colour::RED);
+    }
+
+    #[test]
+    fn it_should_add_to_empty_gradient() {
+        let mut gradient = Gradient::new(vec![]);
+        gradient.add_stop(0.5, Colour::RED);
+        gradient.add_stop(0.0, Colour::BLUE);
+
+        assert_eq!(gradient.sample(0.5), Colour::RED);
+        assert_eq!(gradient.sample(0.0), Colour::BLUE);
+    }
+}
+
+#[cfg(test)]
+mod sample {
+    use super::*;
+
+    #[test]
+    fn it_should_return_stop_colours() {
+        let gradient = new_test_gradient();
+
+        assert_eq!(gradient.sample(0.0), Colour::BLACK);
+        assert_eq!(gradient.sample(0.5), Colour::WHITE);
+        assert_eq!(gradient.sample(1.0), Colour::BLUE);
+    }
+
+    #[test]
+    fn it_should_blend_between_stops() {
+        let gradient = new_test_gradient();
+
+        assert_eq!(
+            gradient.sample(0.25),
+            Colour::new_from_u8s(128, 128, 128, 255)
+        );
+        assert_eq!(
+            gradient.sample(0.75),
+            Colour::new_from_u8s(128, 128, 255, 255)
+        );
+    }
+
+    #[test]
+    fn it_should_blend_in_colour_space() {
+        let gradient = new_test_gradient().with_space(ColourSpace::Oklab);
+
+        assert_eq!(
+            gradient.sample(0.25),
+            Colour::BLACK.mix_in(ColourSpace::Oklab, Colour::WHITE, 0.5)
+        );
+    }
+
+    #[test]
+    fn it_should_apply_easing() {
+        let gradient = new_test_gradient()
+            .with_easing(Easing::EaseIn)
+            .with_segment_easing(1, Easing::Step);
+
+        assert_eq!(gradient.sample(0.25), Colour::new_from_u8s(64, 64, 64, 255));
+        assert_eq!(gradient.sample(0.9), Colour::WHITE);
+    }
+
+    #[test]
+    fn it_should_clamp() {
+        let gradient = new_test_gradient();
+
+        assert_eq!(gradient.sample(-1.0), Colour::BLACK);
+        assert_eq!(gradient.sample(2.5), Colour::BLUE);
+    }
+
+    #[test]
+    fn it_should_repeat() {
+        let gradient = new_test_gradient().with_spread(GradientSpread::Repeat);
+
+        assert_eq!(gradient.sample(1.5), Colour::WHITE);
+        assert_eq!(gradient.sample(-0.5), Colour::WHITE);
+        assert_eq!(gradient.sample(2.0), Colour::BLUE);
+        assert_eq!(gradient.sample(1.25), gradient.sample(0.25));
+    }
+
+    #[test]
+    fn it_should_reflect() {
+        let gradient = new_test_gradient().with_spread(GradientSpread::Reflect);
+
+        assert_eq!(gradient.sample(1.25), gradient.sample(0.75));
+        assert_eq!(gradient.sample(2.0), Colour::BLACK);
+        assert_eq!(gradient.sample(-0.25), gradient.sample(0.25));
+    }
+
+    #[test]
+    fn it_should_use_later_colour_at_hard_stops() {
+        let gradient = Gradient::new(vec![
+            (0.0, Colour::RED),
+            (0.5, Colour::RED),
+            (0.5, Colour::BLUE),
+            (1.0, Colour::BLUE),
+        ]);
+
+        assert_eq!(gradient.sample(0.49), Colour::RED);
+        assert_eq!(gradient.sample(0.5), Colour::BLUE);
+    }
+
+    #[test]
+    fn it_should_use_first_colour_for_nan() {
+        for spread in [
+            GradientSpread::Clamp,
+            GradientSpread::Repeat,
+            GradientSpread::Reflect,
+        ] {
+            let gradient = new_test_gradient().with_spread(spread);
+
+            assert_eq!(gradient.sample(f32::NAN), Colour::BLACK, "{:?}", spread);
+        }
+    }
+
+    #[test]
+    fn it_should_handle_infinity() {
+        let gradient = new_test_gradient();
+        assert_eq!(gradient.sample(f32::INFINITY), Colour::BLUE);
+        assert_eq!(gradient.sample(f32::NEG_INFINITY), Colour::BLACK);
+
+        for spread in [GradientSpread::Repeat, GradientSpread::Reflect] {
+            let gradient = new_test_gradient().with_spread(spread);
+
+            assert_eq!(
+                gradient.sample(f32::INFINITY),
+                Colour::BLACK,
+                "{:?}",
+                spread
+            );
+            assert_eq!(
+                gradient.sample(f32::NEG_INFINITY),
+                Colour::BLACK,
+                "{:?}",
+                spread
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_handle_one_or_no_stops() {
+        assert_eq!(Gradient::new(vec![]).sample(0.5), Colour::new_from_rgba(0));
+        assert_eq!(
+            Gradient::new(vec![(0.5, Colour::RED)]).sample(0.0),
+            Colour::RED
+        );
+    }
+}
+
+#[cfg(test)]
+mod iter_samples {
+    use super::*;
+
+    #[test]
+    fn it_should_sample_evenly_from_start_to_end() {
+        let gradient = new_test_gradient();
+        let samples: Vec<Colour> = gradient.iter_samples(5).collect();
+
+        assert_eq!(
+            samples,
+            vec![
+                gradient.sample(0.0),
+                gradient.sample(0.25),
+                gradient.sample(0.5),
+                gradient.sample(0.75),
+                gradient.sample(1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_know_its_length() {
+        let gradient = new_test_gradient();
+
+        assert_eq!(gradient.iter_samples(7).len(), 7);
+        assert_eq!(gradient.iter_samples(0).count(), 0);
+        assert_eq!(
+            gradient.iter_samples(1).collect::<Vec<_>>(),
+            vec![Colour::BLACK]
+        );
+    }
+}
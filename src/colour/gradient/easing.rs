@@ -0,0 +1,69 @@
+/// How to move between the two colours of a gradient segment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Moves at an even speed.
+    #[default]
+    Linear,
+
+    /// Starts slowly, and speeds up.
+    EaseIn,
+
+    /// Starts quickly, and slows down.
+    EaseOut,
+
+    /// Starts and ends slowly, moving quickest in the middle.
+    EaseInOut,
+
+    /// Holds the first colour for the whole segment, with no blending.
+    Step,
+}
+
+impl Easing {
+    /// Takes a value from 0.0 to 1.0, and returns the eased value.
+    pub fn apply(self, n: f32) -> f32 {
+        match self {
+            Easing::Linear => n,
+            Easing::EaseIn => n * n,
+            Easing::EaseOut => n * (2.0 - n),
+            Easing::EaseInOut => n * n * (3.0 - 2.0 * n),
+            Easing::Step => {
+                if n < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod apply {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_start_and_end() {
+        let easings = [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Step,
+        ];
+
+        for easing in easings {
+            assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(1.0), 1.0, "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn it_should_ease_middle() {
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.15625);
+        assert_eq!(Easing::Step.apply(0.99), 0.0);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::Colour;
+
+use super::Gradient;
+
+/// Iterates over evenly spaced colours of a gradient,
+/// from 0.0 to 1.0 inclusive.
+#[derive(Clone, Debug)]
+pub struct GradientSamplesIterator<'a> {
+    gradient: &'a Gradient,
+    index: usize,
+    num_samples: usize,
+}
+
+impl<'a> GradientSamplesIterator<'a> {
+    pub(crate) fn new(gradient: &'a Gradient, num_samples: usize) -> Self {
+        Self {
+            gradient,
+            index: 0,
+            num_samples,
+        }
+    }
+}
+
+impl<'a> Iterator for GradientSamplesIterator<'a> {
+    type Item = Colour;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.num_samples {
+            return None;
+        }
+
+        let position = if self.num_samples == 1 {
+            0.0
+        } else {
+            self.index as f32 / (self.num_samples - 1) as f32
+        };
+        self.index += 1;
+
+        Some(self.gradient.sample(position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num_samples - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for GradientSamplesIterator<'a> {}
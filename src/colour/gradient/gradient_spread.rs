@@ -0,0 +1,13 @@
+/// What a gradient does when sampled outside of its first and last stops.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum GradientSpread {
+    /// Uses the colour of the nearest end.
+    #[default]
+    Clamp,
+
+    /// Starts again from the beginning.
+    Repeat,
+
+    /// Goes back and forth, like a mirror.
+    Reflect,
+}
@@ -0,0 +1,155 @@
+use crate::Colour;
+
+use super::Hsva;
+
+/// A colour made of hue, saturation, lightness, and alpha.
+///
+/// Every component is from 0.0 to 1.0.
+/// The hue is how far around the colour wheel it is,
+/// where 0.0 is red, 1/3 is green, and 2/3 is blue.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsla {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+    pub alpha: f32,
+}
+
+impl Hsla {
+    pub fn new(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+            alpha,
+        }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        self.to_hsva().to_colour()
+    }
+
+    pub fn to_hsva(self) -> Hsva {
+        Hsva::from(self)
+    }
+
+    /// Returns a copy with the lightness increased by the amount given.
+    pub fn lighten(self, amount: f32) -> Self {
+        Self {
+            lightness: (self.lightness + amount).clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns a copy with the lightness decreased by the amount given.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns a copy with the saturation increased by the amount given.
+    pub fn saturate(self, amount: f32) -> Self {
+        Self {
+            saturation: (self.saturation + amount).clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns a copy with the saturation decreased by the amount given.
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Returns a copy moved around the colour wheel,
+    /// where an amount of 1.0 is a full turn.
+    pub fn rotate_hue(self, amount: f32) -> Self {
+        Self {
+            hue: (self.hue + amount).rem_euclid(1.0),
+            ..self
+        }
+    }
+}
+
+impl From<Colour> for Hsla {
+    fn from(colour: Colour) -> Self {
+        Hsva::from(colour).to_hsla()
+    }
+}
+
+impl From<Hsla> for Colour {
+    fn from(hsla: Hsla) -> Self {
+        hsla.to_colour()
+    }
+}
+
+impl From<Hsva> for Hsla {
+    fn from(hsva: Hsva) -> Self {
+        let lightness = hsva.value * (1.0 - hsva.saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (hsva.value - lightness) / lightness.min(1.0 - lightness)
+        };
+
+        Self::new(hsva.hue, saturation, lightness, hsva.alpha)
+    }
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_convert_primary_colours() {
+        assert_eq!(Hsla::from(Colour::RED), Hsla::new(0.0, 1.0, 0.5, 1.0));
+        assert_eq!(Hsla::from(Colour::WHITE), Hsla::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(Hsla::from(Colour::BLACK), Hsla::new(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn it_should_convert_pastel_colour() {
+        let hsla = Hsla::from(Colour::LIGHT_RED);
+
+        assert_approx_eq!(hsla.hue, 0.0);
+        assert_approx_eq!(hsla.saturation, 1.0);
+        assert_approx_eq!(hsla.lightness, 0.7, 0.001);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    let colour = Colour::new_from_u8s(red, green, blue, 200);
+
+                    assert_eq!(Hsla::from(colour).to_colour(), colour);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod saturate {
+    use super::*;
+
+    #[test]
+    fn it_should_change_saturation() {
+        let hsla = Hsla::new(0.5, 0.5, 0.5, 1.0);
+
+        assert_eq!(hsla.saturate(0.25).saturation, 0.75);
+        assert_eq!(hsla.desaturate(0.25).saturation, 0.25);
+        assert_eq!(hsla.saturate(1.0).saturation, 1.0);
+        assert_eq!(hsla.desaturate(1.0).saturation, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod to_colour {
+    use super::*;
+
+    #[test]
+    fn it_should_treat_hue_just_below_zero_as_red() {
+        assert_eq!(Hsla::new(-1e-9, 1.0, 0.5, 1.0).to_colour(), Colour::RED);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::Colour;
+
+use super::Hsla;
+use super::Rgba;
+
+/// A colour made of hue, saturation, value, and alpha.
+///
+/// Every component is from 0.0 to 1.0.
+/// The hue is how far around the colour wheel it is,
+/// where 0.0 is red, 1/3 is green, and 2/3 is blue.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsva {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+    pub alpha: f32,
+}
+
+impl Hsva {
+    pub fn new(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+            alpha,
+        }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        let hue = self.hue.rem_euclid(1.0) * 6.0;
+        let saturation = self.saturation.clamp(0.0, 1.0);
+        let value = self.value.clamp(0.0, 1.0);
+
+        let sector = hue.floor();
+        let fraction = hue - sector;
+        let p = value * (1.0 - saturation);
+        let q = value * (1.0 - saturation * fraction);
+        let t = value * (1.0 - saturation * (1.0 - fraction));
+
+        // A tiny negative hue wraps to exactly 1.0, giving a sector of 6, which is red.
+        let (red, green, blue) = match (sector as u32) % 6 {
+            0 => (value, t, p),
+            1 => (q, value, p),
+            2 => (p, value, t),
+            3 => (p, q, value),
+            4 => (t, p, value),
+            _ => (value, p, q),
+        };
+
+        Colour::new_from_f32s_rounded(red, green, blue, self.alpha)
+    }
+
+    pub fn to_hsla(self) -> Hsla {
+        Hsla::from(self)
+    }
+
+    pub fn to_rgba(self) -> Rgba {
+        Rgba::from(self.to_colour())
+    }
+
+    /// Returns a copy with the value increased by the amount given.
+    pub fn lighten(self, amount: f32) -> Self {
+        Self {
+            value: (self.value + amount).clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Returns a copy with the value decreased by the amount given.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns a copy moved around the colour wheel,
+    /// where an amount of 1.0 is a full turn.
+    pub fn rotate_hue(self, amount: f32) -> Self {
+        Self {
+            hue: (self.hue + amount).rem_euclid(1.0),
+            ..self
+        }
+    }
+}
+
+impl From<Colour> for Hsva {
+    fn from(colour: Colour) -> Self {
+        let red = colour.red_f32();
+        let green = colour.green_f32();
+        let blue = colour.blue_f32();
+        let alpha = colour.alpha_f32();
+
+        let value = red.max(green).max(blue);
+        let diff = value - red.min(green).min(blue);
+        if diff == 0.0 {
+            return Self::new(0.0, 0.0, value, alpha);
+        }
+
+        let hue = if value == red {
+            ((green - blue) / diff).rem_euclid(6.0)
+        } else if value == green {
+            (blue - red) / diff + 2.0
+        } else {
+            (red - green) / diff + 4.0
+        };
+
+        Self::new(hue / 6.0, diff / value, value, alpha)
+    }
+}
+
+impl From<Hsva> for Colour {
+    fn from(hsva: Hsva) -> Self {
+        hsva.to_colour()
+    }
+}
+
+impl From<Hsla> for Hsva {
+    fn from(hsla: Hsla) -> Self {
+        let value = hsla.lightness + hsla.saturation * hsla.lightness.min(1.0 - hsla.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - hsla.lightness / value)
+        };
+
+        Self::new(hsla.hue, saturation, value, hsla.alpha)
+    }
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_convert_primary_colours() {
+        assert_eq!(Hsva::from(Colour::RED), Hsva::new(0.0, 1.0, 1.0, 1.0));
+        assert_approx_eq!(Hsva::from(Colour::GREEN).hue, 1.0 / 3.0);
+        assert_approx_eq!(Hsva::from(Colour::BLUE).hue, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn it_should_have_no_saturation_for_greys() {
+        let hsva = Hsva::from(Colour::GREY);
+
+        assert_eq!(hsva.hue, 0.0);
+        assert_eq!(hsva.saturation, 0.0);
+        assert_approx_eq!(hsva.value, 128.0 / 255.0);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    let colour = Colour::new_from_u8s(red, green, blue, 99);
+
+                    assert_eq!(Hsva::from(colour).to_colour(), colour);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod from_hsla {
+    use super::*;
+
+    #[test]
+    fn it_should_match_converting_through_colour() {
+        let colour = Colour::new_from_u8s(200, 120, 30, 255);
+        let hsva = Hsva::from(Hsla::from(colour));
+
+        assert_eq!(hsva.to_colour(), colour);
+    }
+}
+
+#[cfg(test)]
+mod lighten {
+    use super::*;
+
+    #[test]
+    fn it_should_increase_value() {
+        let hsva = Hsva::new(0.5, 0.5, 0.25, 1.0);
+
+        assert_eq!(hsva.lighten(0.5), Hsva::new(0.5, 0.5, 0.75, 1.0));
+        assert_eq!(hsva.lighten(2.0).value, 1.0);
+        assert_eq!(hsva.darken(1.0).value, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod rotate_hue {
+    use super::*;
+
+    #[test]
+    fn it_should_wrap_around() {
+        let hsva = Hsva::new(0.75, 1.0, 1.0, 1.0);
+
+        assert_eq!(hsva.rotate_hue(0.5).hue, 0.25);
+        assert_eq!(hsva.rotate_hue(-1.0).hue, 0.75);
+    }
+}
+
+#[cfg(test)]
+mod to_colour {
+    use super::*;
+
+    #[test]
+    fn it_should_treat_hue_just_below_zero_as_red() {
+        assert_eq!(Hsva::new(-1e-9, 1.0, 1.0, 1.0).to_colour(), Colour::RED);
+        assert_eq!(Hsva::new(1.0, 1.0, 1.0, 1.0).to_colour(), Colour::RED);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::Colour;
+
+use super::linear_rgba::mix_f32;
+use super::LinearRgba;
+
+/// A colour in the CIELAB colour space, using the D65 white point.
+///
+/// `l` is the lightness, from 0.0 to 100.0.
+/// `a` goes from green to red, and `b` goes from blue to yellow.
+/// Both are roughly between -128.0 and 128.0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+/// The D65 white point, in XYZ.
+const WHITE_X: f32 = 0.950_47;
+const WHITE_Y: f32 = 1.0;
+const WHITE_Z: f32 = 1.088_83;
+
+const EPSILON: f32 = 6.0 / 29.0;
+
+impl Lab {
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        self.to_linear_rgba().to_colour()
+    }
+
+    pub fn to_linear_rgba(self) -> LinearRgba {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        let x = lab_f_inverse(fx) * WHITE_X;
+        let y = lab_f_inverse(fy) * WHITE_Y;
+        let z = lab_f_inverse(fz) * WHITE_Z;
+
+        LinearRgba::new(
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+            self.alpha,
+        )
+    }
+
+    /// Blends each component, where an amount of 0.0 is this colour,
+    /// and 1.0 is the other.
+    pub fn mix(self, other: Self, amount: f32) -> Self {
+        Self::new(
+            mix_f32(self.l, other.l, amount),
+            mix_f32(self.a, other.a, amount),
+            mix_f32(self.b, other.b, amount),
+            mix_f32(self.alpha, other.alpha, amount),
+        )
+    }
+
+    /// The perceived difference between two colours, using CIEDE2000.
+    ///
+    /// A difference below 1.0 is not noticeable to most people.
+    /// Alpha is ignored.
+    ///
+    /// See: https://www.ece.rochester.edu/~gsharma/ciede2000/
+    pub fn delta_e(self, other: Self) -> f32 {
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+
+        let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let c_mean_7 = c_mean.powi(7);
+        let g = 0.5 * (1.0 - (c_mean_7 / (c_mean_7 + 25.0_f64.powi(7))).sqrt());
+
+        let a1 = a1 * (1.0 + g);
+        let a2 = a2 * (1.0 + g);
+        let c1 = a1.hypot(b1);
+        let c2 = a2.hypot(b2);
+        let h1 = hue_degrees(a1, b1);
+        let h2 = hue_degrees(a2, b2);
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 <= h1 {
+            h2 - h1 + 360.0
+        } else {
+            h2 - h1 - 360.0
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+
+        let l_mean_offset = (l_mean - 50.0).powi(2);
+        let s_l = 1.0 + (0.015 * l_mean_offset) / (20.0 + l_mean_offset).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+
+        let c_mean_7 = c_mean.powi(7);
+        let r_c = 2.0 * (c_mean_7 / (c_mean_7 + 25.0_f64.powi(7))).sqrt();
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let r_t = -r_c * (2.0 * delta_theta).to_radians().sin();
+
+        let l_term = delta_l / s_l;
+        let c_term = delta_c / s_c;
+        let h_term = delta_h / s_h;
+
+        (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt() as f32
+    }
+}
+
+impl From<LinearRgba> for Lab {
+    fn from(linear: LinearRgba) -> Self {
+        let LinearRgba {
+            red,
+            green,
+            blue,
+            alpha,
+        } = linear;
+
+        let x = 0.412_456_4 * red + 0.357_576_1 * green + 0.180_437_5 * blue;
+        let y = 0.212_672_9 * red + 0.715_152_2 * green + 0.072_175 * blue;
+        let z = 0.019_333_9 * red + 0.119_192 * green + 0.950_304_1 * blue;
+
+        let fx = lab_f(x / WHITE_X);
+        let fy = lab_f(y / WHITE_Y);
+        let fz = lab_f(z / WHITE_Z);
+
+        Self::new(
+            116.0 * fy - 16.0,
+            500.0 * (fx - fy),
+            200.0 * (fy - fz),
+            alpha,
+        )
+    }
+}
+
+impl From<Colour> for Lab {
+    fn from(colour: Colour) -> Self {
+        Self::from(LinearRgba::from(colour))
+    }
+}
+
+impl From<Lab> for Colour {
+    fn from(lab: Lab) -> Self {
+        lab.to_colour()
+    }
+}
+
+fn lab_f(t: f32) -> f32 {
+    if t > EPSILON * EPSILON * EPSILON {
+        t.cbrt()
+    } else {
+        t / (3.0 * EPSILON * EPSILON) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inverse(t: f32) -> f32 {
+    if t > EPSILON {
+        t * t * t
+    } else {
+        3.0 * EPSILON * EPSILON * (t - 4.0 / 29.0)
+    }
+}
+
+/// The angle of a and b, from 0 to 360 degrees.
+fn hue_degrees(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    }
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_match_reference_values() {
+        let white = Lab::from(Colour::WHITE);
+        assert_approx_eq!(white.l, 100.0, 0.01);
+        assert_approx_eq!(white.a, 0.0, 0.01);
+        assert_approx_eq!(white.b, 0.0, 0.01);
+
+        let red = Lab::from(Colour::RED);
+        assert_approx_eq!(red.l, 53.24, 0.01);
+        assert_approx_eq!(red.a, 80.09, 0.01);
+        assert_approx_eq!(red.b, 67.20, 0.01);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    let colour = Colour::new_from_u8s(red, green, blue, 255);
+
+                    assert_eq!(Lab::from(colour).to_colour(), colour);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod delta_e {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    /// Pairs from the CIEDE2000 test data, by Sharma, Wu and Dalal.
+    #[test]
+    fn it_should_match_reference_values() {
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, 2.5, 0.0), (50.0, 3.2592, 0.335), 1.0),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                (22.7233, 20.0904, -46.694),
+                (23.0331, 14.973, -42.5619),
+                2.0373,
+            ),
+        ];
+
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lab_1 = Lab::new(l1, a1, b1, 1.0);
+            let lab_2 = Lab::new(l2, a2, b2, 1.0);
+
+            assert_approx_eq!(lab_1.delta_e(lab_2), expected, 0.0001);
+            assert_approx_eq!(lab_2.delta_e(lab_1), expected, 0.0001);
+        }
+    }
+
+    #[test]
+    fn it_should_be_zero_for_same_colour() {
+        let lab = Lab::from(Colour::ORANGE);
+
+        assert_eq!(lab.delta_e(lab), 0.0);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::Colour;
+
+/// A colour in linear sRGB, where each component is proportional to the light given off.
+///
+/// Components are from 0.0 to 1.0, although values outside of this are kept,
+/// and are clamped when converted back into a `Colour`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LinearRgba {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+impl LinearRgba {
+    pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        Colour::new_from_f32s_rounded(
+            linear_to_srgb(self.red),
+            linear_to_srgb(self.green),
+            linear_to_srgb(self.blue),
+            self.alpha,
+        )
+    }
+
+    /// Blends each component, where an amount of 0.0 is this colour,
+    /// and 1.0 is the other.
+    pub fn mix(self, other: Self, amount: f32) -> Self {
+        Self::new(
+            mix_f32(self.red, other.red, amount),
+            mix_f32(self.green, other.green, amount),
+            mix_f32(self.blue, other.blue, amount),
+            mix_f32(self.alpha, other.alpha, amount),
+        )
+    }
+}
+
+impl From<Colour> for LinearRgba {
+    fn from(colour: Colour) -> Self {
+        Self::new(
+            srgb_to_linear(colour.red_f32()),
+            srgb_to_linear(colour.green_f32()),
+            srgb_to_linear(colour.blue_f32()),
+            colour.alpha_f32(),
+        )
+    }
+}
+
+impl From<LinearRgba> for Colour {
+    fn from(linear: LinearRgba) -> Self {
+        linear.to_colour()
+    }
+}
+
+/// Removes the sRGB gamma curve.
+pub(crate) fn srgb_to_linear(n: f32) -> f32 {
+    if n <= 0.04045 {
+        n / 12.92
+    } else {
+        ((n + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applies the sRGB gamma curve.
+fn linear_to_srgb(n: f32) -> f32 {
+    if n <= 0.0031308 {
+        n * 12.92
+    } else {
+        1.055 * n.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub(crate) fn mix_f32(from: f32, to: f32, amount: f32) -> f32 {
+    from + (to - from) * amount
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_remove_gamma() {
+        let linear = LinearRgba::from(Colour::GREY);
+
+        assert_approx_eq!(linear.red, 0.2158605, 0.00001);
+        assert_eq!(
+            LinearRgba::from(Colour::WHITE),
+            LinearRgba::new(1.0, 1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            LinearRgba::from(Colour::BLACK),
+            LinearRgba::new(0.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for n in 0..=255 {
+            let colour = Colour::new_from_u8s(n, 255 - n, n / 2, n);
+
+            assert_eq!(LinearRgba::from(colour).to_colour(), colour);
+        }
+    }
+}
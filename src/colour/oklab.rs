@@ -0,0 +1,128 @@
+use crate::Colour;
+
+use super::linear_rgba::mix_f32;
+use super::LinearRgba;
+use super::Oklch;
+
+/// A colour in the Oklab perceptual colour space.
+///
+/// `l` is the lightness, from 0.0 to 1.0.
+/// `a` goes from green to red, and `b` goes from blue to yellow.
+/// Both are roughly between -0.4 and 0.4.
+///
+/// See: https://bottosson.github.io/posts/oklab/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        self.to_linear_rgba().to_colour()
+    }
+
+    pub fn to_linear_rgba(self) -> LinearRgba {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let l = l * l * l;
+        let m = m * m * m;
+        let s = s * s * s;
+
+        LinearRgba::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            self.alpha,
+        )
+    }
+
+    pub fn to_oklch(self) -> Oklch {
+        Oklch::from(self)
+    }
+
+    /// Blends each component, where an amount of 0.0 is this colour,
+    /// and 1.0 is the other.
+    pub fn mix(self, other: Self, amount: f32) -> Self {
+        Self::new(
+            mix_f32(self.l, other.l, amount),
+            mix_f32(self.a, other.a, amount),
+            mix_f32(self.b, other.b, amount),
+            mix_f32(self.alpha, other.alpha, amount),
+        )
+    }
+}
+
+impl From<LinearRgba> for Oklab {
+    fn from(linear: LinearRgba) -> Self {
+        let LinearRgba {
+            red,
+            green,
+            blue,
+            alpha,
+        } = linear;
+
+        let l = (0.412_221_46 * red + 0.536_332_55 * green + 0.051_445_995 * blue).cbrt();
+        let m = (0.211_903_5 * red + 0.680_699_5 * green + 0.107_396_96 * blue).cbrt();
+        let s = (0.088_302_46 * red + 0.281_718_85 * green + 0.629_978_7 * blue).cbrt();
+
+        Self::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        )
+    }
+}
+
+impl From<Colour> for Oklab {
+    fn from(colour: Colour) -> Self {
+        Self::from(LinearRgba::from(colour))
+    }
+}
+
+impl From<Oklab> for Colour {
+    fn from(oklab: Oklab) -> Self {
+        oklab.to_colour()
+    }
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_match_reference_values() {
+        let white = Oklab::from(Colour::WHITE);
+        assert_approx_eq!(white.l, 1.0, 0.0001);
+        assert_approx_eq!(white.a, 0.0, 0.0001);
+        assert_approx_eq!(white.b, 0.0, 0.0001);
+
+        let red = Oklab::from(Colour::RED);
+        assert_approx_eq!(red.l, 0.627955, 0.0001);
+        assert_approx_eq!(red.a, 0.224863, 0.0001);
+        assert_approx_eq!(red.b, 0.125846, 0.0001);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    let colour = Colour::new_from_u8s(red, green, blue, 255);
+
+                    assert_eq!(Oklab::from(colour).to_colour(), colour);
+                }
+            }
+        }
+    }
+}
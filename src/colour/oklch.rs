@@ -0,0 +1,159 @@
+use std::f32::consts::TAU;
+
+use crate::Colour;
+
+use super::linear_rgba::mix_f32;
+use super::Oklab;
+
+/// A colour in the Oklab colour space, using polar coordinates.
+///
+/// `lightness` is from 0.0 to 1.0, and `chroma` is roughly from 0.0 to 0.4.
+/// The `hue` is how far around the colour wheel it is, from 0.0 to 1.0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Oklch {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+    pub alpha: f32,
+}
+
+/// Below this chroma, the colour is treated as grey and the hue is ignored when mixing.
+const GREY_CHROMA: f32 = 0.0001;
+
+impl Oklch {
+    pub fn new(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        Self {
+            lightness,
+            chroma,
+            hue,
+            alpha,
+        }
+    }
+
+    pub fn to_colour(self) -> Colour {
+        self.to_oklab().to_colour()
+    }
+
+    pub fn to_oklab(self) -> Oklab {
+        let angle = self.hue * TAU;
+
+        Oklab::new(
+            self.lightness,
+            self.chroma * angle.cos(),
+            self.chroma * angle.sin(),
+            self.alpha,
+        )
+    }
+
+    /// Blends each component, where an amount of 0.0 is this colour,
+    /// and 1.0 is the other.
+    ///
+    /// The hue takes the shortest way around the colour wheel.
+    /// If either colour is grey, the hue of the other is used.
+    pub fn mix(self, other: Self, amount: f32) -> Self {
+        let (from_hue, to_hue) = match (self.chroma < GREY_CHROMA, other.chroma < GREY_CHROMA) {
+            (true, false) => (other.hue, other.hue),
+            (false, true) => (self.hue, self.hue),
+            _ => (self.hue, other.hue),
+        };
+
+        Self::new(
+            mix_f32(self.lightness, other.lightness, amount),
+            mix_f32(self.chroma, other.chroma, amount),
+            mix_hue(from_hue, to_hue, amount),
+            mix_f32(self.alpha, other.alpha, amount),
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let chroma = oklab.a.hypot(oklab.b);
+        let hue = if chroma < GREY_CHROMA {
+            0.0
+        } else {
+            (oklab.b.atan2(oklab.a) / TAU).rem_euclid(1.0)
+        };
+
+        Self::new(oklab.l, chroma, hue, oklab.alpha)
+    }
+}
+
+impl From<Colour> for Oklch {
+    fn from(colour: Colour) -> Self {
+        Self::from(Oklab::from(colour))
+    }
+}
+
+impl From<Oklch> for Colour {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_colour()
+    }
+}
+
+/// Mixes two hues, going the shortest way around the colour wheel.
+pub(crate) fn mix_hue(from: f32, to: f32, amount: f32) -> f32 {
+    let diff = (to - from + 0.5).rem_euclid(1.0) - 0.5;
+
+    (from + diff * amount).rem_euclid(1.0)
+}
+
+#[cfg(test)]
+mod from_colour {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_match_reference_values() {
+        let red = Oklch::from(Colour::RED);
+
+        assert_approx_eq!(red.lightness, 0.627955, 0.0001);
+        assert_approx_eq!(red.chroma, 0.257683, 0.0001);
+        assert_approx_eq!(red.hue * 360.0, 29.2339, 0.01);
+    }
+
+    #[test]
+    fn it_should_have_no_hue_for_greys() {
+        let grey = Oklch::from(Colour::GREY);
+
+        assert_eq!(grey.hue, 0.0);
+        assert_approx_eq!(grey.chroma, 0.0, 0.0001);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_colour() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    let colour = Colour::new_from_u8s(red, green, blue, 255);
+
+                    assert_eq!(Oklch::from(colour).to_colour(), colour);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod mix {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_take_shortest_way_around_hue() {
+        let from = Oklch::new(0.5, 0.1, 0.9, 1.0);
+        let to = Oklch::new(0.5, 0.1, 0.1, 1.0);
+
+        assert_approx_eq!(from.mix(to, 0.5).hue, 0.0);
+        assert_approx_eq!(from.mix(to, 0.25).hue, 0.95);
+    }
+
+    #[test]
+    fn it_should_keep_hue_when_mixing_with_grey() {
+        let from = Oklch::new(0.5, 0.2, 0.3, 1.0);
+        let grey = Oklch::new(1.0, 0.0, 0.0, 1.0);
+
+        assert_approx_eq!(from.mix(grey, 0.5).hue, 0.3);
+        assert_approx_eq!(grey.mix(from, 0.5).hue, 0.3);
+    }
+}
@@ -0,0 +1,296 @@
+use crate::Colour;
+
+/// The order, and size, of colour channels when packed into an integer.
+///
+/// The 32 bit formats are named from the most significant byte to the least,
+/// so `Argb8888` packs into `0xAARRGGBB`.
+/// The 16 bit formats are packed the same way,
+/// with red in the most significant bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum PixelFormat {
+    /// The same as `Colour::new_from_rgba`.
+    #[default]
+    Rgba8888,
+    Argb8888,
+    Bgra8888,
+    Abgr8888,
+
+    /// 5 bits of red, 6 of green, and 5 of blue, and fully opaque.
+    Rgb565,
+
+    /// 4 bits for each channel.
+    Rgba4444,
+}
+
+impl PixelFormat {
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Rgb565 | PixelFormat::Rgba4444 => 2,
+            _ => 4,
+        }
+    }
+
+    /// Writes out each colour as bytes.
+    ///
+    /// The 32 bit formats write the bytes in the order they are named.
+    /// The 16 bit formats are little endian, as most hardware expects.
+    pub fn colours_to_bytes(self, colours: &[Colour]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(colours.len() * self.bytes_per_pixel());
+
+        for colour in colours {
+            let packed = colour.to_packed(self);
+
+            match self.bytes_per_pixel() {
+                2 => bytes.extend((packed as u16).to_le_bytes()),
+                _ => bytes.extend(packed.to_be_bytes()),
+            }
+        }
+
+        bytes
+    }
+
+    /// Reads colours from bytes, the reverse of `colours_to_bytes`.
+    ///
+    /// Any bytes left over at the end, which are too few for a whole pixel, are ignored.
+    pub fn colours_from_bytes(self, bytes: &[u8]) -> Vec<Colour> {
+        bytes
+            .chunks_exact(self.bytes_per_pixel())
+            .map(|chunk| {
+                let packed = match *chunk {
+                    [a, b] => u16::from_le_bytes([a, b]) as u32,
+                    [a, b, c, d] => u32::from_be_bytes([a, b, c, d]),
+                    _ => unreachable!(),
+                };
+
+                Colour::from_packed(packed, self)
+            })
+            .collect()
+    }
+}
+
+impl Colour {
+    /// Unpacks a colour, such as from a `u32` or `u16`, in the format given.
+    ///
+    /// The 16 bit formats only use the lowest 16 bits.
+    pub fn from_packed<P>(packed: P, format: PixelFormat) -> Self
+    where
+        P: Into<u32>,
+    {
+        let packed = packed.into();
+        let byte = |shift: u32| (packed >> shift) as u8;
+
+        match format {
+            PixelFormat::Rgba8888 => Colour::new_from_rgba(packed),
+            PixelFormat::Argb8888 => Colour::new_from_u8s(byte(16), byte(8), byte(0), byte(24)),
+            PixelFormat::Bgra8888 => Colour::new_from_u8s(byte(8), byte(16), byte(24), byte(0)),
+            PixelFormat::Abgr8888 => Colour::new_from_u8s(byte(0), byte(8), byte(16), byte(24)),
+            PixelFormat::Rgb565 => Colour::new_from_u8s(
+                expand_bits(packed >> 11, 5),
+                expand_bits(packed >> 5, 6),
+                expand_bits(packed, 5),
+                255,
+            ),
+            PixelFormat::Rgba4444 => Colour::new_from_u8s(
+                expand_bits(packed >> 12, 4),
+                expand_bits(packed >> 8, 4),
+                expand_bits(packed >> 4, 4),
+                expand_bits(packed, 4),
+            ),
+        }
+    }
+
+    /// Packs this colour into the format given.
+    ///
+    /// The 16 bit formats lose precision, rounding to the nearest value,
+    /// and `Rgb565` drops alpha.
+    pub fn to_packed(self, format: PixelFormat) -> u32 {
+        let (red, green, blue, alpha) = (
+            self.red_u32(),
+            self.green_u32(),
+            self.blue_u32(),
+            self.alpha_u32(),
+        );
+
+        match format {
+            PixelFormat::Rgba8888 => self.to_rgba_u32(),
+            PixelFormat::Argb8888 => (alpha << 24) | (red << 16) | (green << 8) | blue,
+            PixelFormat::Bgra8888 => (blue << 24) | (green << 16) | (red << 8) | alpha,
+            PixelFormat::Abgr8888 => (alpha << 24) | (blue << 16) | (green << 8) | red,
+            PixelFormat::Rgb565 => {
+                (shrink_bits(red, 5) << 11) | (shrink_bits(green, 6) << 5) | shrink_bits(blue, 5)
+            }
+            PixelFormat::Rgba4444 => {
+                (shrink_bits(red, 4) << 12)
+                    | (shrink_bits(green, 4) << 8)
+                    | (shrink_bits(blue, 4) << 4)
+                    | shrink_bits(alpha, 4)
+            }
+        }
+    }
+}
+
+/// Scales the lowest bits of the value up to 0 to 255.
+fn expand_bits(value: u32, bits: u32) -> u8 {
+    let max = (1 << bits) - 1;
+    let value = value & max;
+
+    ((value * 255 + max / 2) / max) as u8
+}
+
+/// Scales a value from 0 to 255 down to fit in the bits given.
+fn shrink_bits(value: u32, bits: u32) -> u32 {
+    let max = (1 << bits) - 1;
+
+    (value * max + 127) / 255
+}
+
+#[cfg(test)]
+mod from_packed {
+    use super::*;
+
+    #[test]
+    fn it_should_unpack_32_bit_formats() {
+        let colour = Colour::new_from_u8s(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(
+            Colour::from_packed(0x11223344_u32, PixelFormat::Rgba8888),
+            colour
+        );
+        assert_eq!(
+            Colour::from_packed(0x44112233_u32, PixelFormat::Argb8888),
+            colour
+        );
+        assert_eq!(
+            Colour::from_packed(0x33221144_u32, PixelFormat::Bgra8888),
+            colour
+        );
+        assert_eq!(
+            Colour::from_packed(0x44332211_u32, PixelFormat::Abgr8888),
+            colour
+        );
+    }
+
+    #[test]
+    fn it_should_unpack_16_bit_formats() {
+        assert_eq!(
+            Colour::from_packed(0xf800_u16, PixelFormat::Rgb565),
+            Colour::RED
+        );
+        assert_eq!(
+            Colour::from_packed(0x07e0_u16, PixelFormat::Rgb565),
+            Colour::GREEN
+        );
+        assert_eq!(
+            Colour::from_packed(0xffff_u16, PixelFormat::Rgb565),
+            Colour::WHITE
+        );
+        assert_eq!(
+            Colour::from_packed(0x0f8a_u16, PixelFormat::Rgba4444),
+            Colour::new_from_u8s(0x00, 0xff, 0x88, 0xaa)
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_packed {
+    use super::*;
+
+    #[test]
+    fn it_should_pack_32_bit_formats() {
+        let colour = Colour::new_from_u8s(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(colour.to_packed(PixelFormat::Rgba8888), 0x11223344);
+        assert_eq!(colour.to_packed(PixelFormat::Argb8888), 0x44112233);
+        assert_eq!(colour.to_packed(PixelFormat::Bgra8888), 0x33221144);
+        assert_eq!(colour.to_packed(PixelFormat::Abgr8888), 0x44332211);
+    }
+
+    #[test]
+    fn it_should_pack_16_bit_formats() {
+        assert_eq!(Colour::BLUE.to_packed(PixelFormat::Rgb565), 0x001f);
+        assert_eq!(Colour::GREY.to_packed(PixelFormat::Rgb565), 0x8410);
+        assert_eq!(
+            Colour::new_from_u8s(0x00, 0xff, 0x88, 0xaa).to_packed(PixelFormat::Rgba4444),
+            0x0f8a
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_every_format() {
+        let formats = [
+            PixelFormat::Rgba8888,
+            PixelFormat::Argb8888,
+            PixelFormat::Bgra8888,
+            PixelFormat::Abgr8888,
+            PixelFormat::Rgb565,
+            PixelFormat::Rgba4444,
+        ];
+
+        for format in formats {
+            for packed in [0_u32, 0x1234, 0xbeef, 0xffff] {
+                let colour = Colour::from_packed(packed, format);
+
+                assert_eq!(
+                    Colour::from_packed(colour.to_packed(format), format),
+                    colour,
+                    "{:?} {:x}",
+                    format,
+                    packed
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod colours_to_bytes {
+    use super::*;
+
+    #[test]
+    fn it_should_write_bytes_in_order() {
+        let colours = [
+            Colour::new_from_u8s(1, 2, 3, 4),
+            Colour::new_from_u8s(5, 6, 7, 8),
+        ];
+
+        assert_eq!(
+            PixelFormat::Bgra8888.colours_to_bytes(&colours),
+            vec![3, 2, 1, 4, 7, 6, 5, 8]
+        );
+        assert_eq!(
+            PixelFormat::Rgb565.colours_to_bytes(&[Colour::RED]),
+            vec![0x00, 0xf8]
+        );
+    }
+}
+
+#[cfg(test)]
+mod colours_from_bytes {
+    use super::*;
+
+    #[test]
+    fn it_should_read_whole_pixels() {
+        let bytes = [4, 1, 2, 3, 8, 5, 6, 7, 9];
+
+        assert_eq!(
+            PixelFormat::Argb8888.colours_from_bytes(&bytes),
+            vec![
+                Colour::new_from_u8s(1, 2, 3, 4),
+                Colour::new_from_u8s(5, 6, 7, 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip() {
+        let colours = vec![Colour::RED, Colour::CYAN, Colour::new_from_rgba(0x11223344)];
+
+        for format in [PixelFormat::Abgr8888, PixelFormat::Rgba4444] {
+            let bytes = format.colours_to_bytes(&colours);
+            let read = format.colours_from_bytes(&bytes);
+
+            assert_eq!(read.len(), colours.len());
+            assert_eq!(format.colours_to_bytes(&read), bytes);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Colour;
+
+use super::Hsva;
+
+/// An RGBA colour, with each component available to read and write.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rgba {
+    /// The red component.
+    /// 0 to 255.
+    ///
+    pub red: u8,
+
+    /// The green component.
+    /// 0 to 255.
+    ///
+    pub green: u8,
+
+    /// The blue component.
+    /// 0 to 255.
+    ///
+    pub blue: u8,
+
+    /// The alpha component.
+    /// 0 to 255.
+    ///
+    pub alpha: u8,
+}
+
+impl Rgba {
+    /// Trivial constructor.
+    ///
+    pub fn new_rgb(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            blue: b,
+            green: g,
+            alpha: 255,
+        }
+    }
+
+    /// Returns the HSVA equivalent of the RGBA object.
+    ///
+    pub fn to_hsva(&self) -> Hsva {
+        Hsva::from(self.to_colour())
+    }
+
+    pub fn to_colour(&self) -> Colour {
+        Colour::new_from_u8s(self.red, self.green, self.blue, self.alpha)
+    }
+
+    /// Clones this colour, with the new opacity.
+    ///
+    pub fn opacity(&self, alpha: u8) -> Rgba {
+        Rgba {
+            red: self.red,
+            blue: self.blue,
+            green: self.green,
+            alpha,
+        }
+    }
+
+    /// Returns a copy with the HSL lightness increased by the amount given,
+    /// the same as `Colour::lighten`.
+    ///
+    pub fn lighten(&self, amount: f32) -> Rgba {
+        self.to_colour().lighten(amount).into()
+    }
+
+    /// Returns a copy with the HSL lightness decreased by the amount given,
+    /// the same as `Colour::darken`.
+    ///
+    pub fn darken(&self, amount: f32) -> Rgba {
+        self.to_colour().darken(amount).into()
+    }
+}
+
+impl From<Colour> for Rgba {
+    fn from(colour: Colour) -> Self {
+        Rgba {
+            red: colour.red_u8(),
+            green: colour.green_u8(),
+            blue: colour.blue_u8(),
+            alpha: colour.alpha_u8(),
+        }
+    }
+}
+
+impl From<Rgba> for Colour {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_colour()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create() {
+        let rgba = Rgba {
+            red: 255,
+            green: 200,
+            blue: 99,
+            alpha: 200,
+        };
+
+        assert_eq!(rgba, rgba.to_hsva().to_rgba());
+    }
+
+    #[test]
+    fn it_should_lighten_and_darken_lightness() {
+        let rgba = Rgba::new_rgb(100, 25, 0);
+
+        assert_eq!(rgba.lighten(1.0), Rgba::new_rgb(255, 255, 255));
+        assert_eq!(rgba.darken(1.0), Rgba::new_rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn it_should_keep_the_hue() {
+        let rgba = Rgba::new_rgb(100, 25, 0);
+
+        assert_eq!(rgba.lighten(0.2), Rgba::new_rgb(202, 51, 0));
+        assert_eq!(rgba.darken(0.1), Rgba::new_rgb(49, 12, 0));
+    }
+}
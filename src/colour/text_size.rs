@@ -0,0 +1,9 @@
+/// Text size, as WCAG uses for the contrast needed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum TextSize {
+    #[default]
+    Normal,
+
+    /// At least 18 point, or 14 point and bold.
+    Large,
+}
@@ -0,0 +1,25 @@
+use super::TextSize;
+
+/// The WCAG 2 conformance levels for contrast.
+///
+/// See: https://www.w3.org/TR/WCAG21/#contrast-minimum
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// The minimum level, which most guidelines ask for.
+    AA,
+
+    /// The enhanced level.
+    AAA,
+}
+
+impl WcagLevel {
+    /// The lowest contrast ratio needed to pass, for text of the size given.
+    pub fn min_contrast_ratio(self, text_size: TextSize) -> f32 {
+        match (self, text_size) {
+            (WcagLevel::AA, TextSize::Normal) => 4.5,
+            (WcagLevel::AA, TextSize::Large) => 3.0,
+            (WcagLevel::AAA, TextSize::Normal) => 7.0,
+            (WcagLevel::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
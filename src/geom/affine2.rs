@@ -0,0 +1,365 @@
+use std::ops::Mul;
+use std::ops::MulAssign;
+
+use crate::num::Num;
+
+use crate::geom::BCurve;
+use crate::geom::Circle;
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Polygon;
+use crate::geom::Rect;
+use crate::geom::Size;
+use crate::geom::Transform;
+
+/// A 2D affine transformation, stored as the top two rows of a 3x3 matrix.
+///
+/// ```text
+/// | a  c  tx |
+/// | b  d  ty |
+/// | 0  0  1  |
+/// ```
+///
+/// Multiplying two together composes them.
+/// `a * b` will apply `b` first, and then `a`.
+/// Use `then` if you want to read them in the order they are applied.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine2 {
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+    tx: f32,
+    ty: f32,
+}
+
+impl Affine2 {
+    pub const IDENTITY: Affine2 = Affine2::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    pub const fn new(a: f32, b: f32, c: f32, d: f32, tx: f32, ty: f32) -> Self {
+        Self { a, b, c, d, tx, ty }
+    }
+
+    pub fn new_translation<N: Num>(position: Point<N>) -> Self {
+        let Point(x, y) = position.to_f32();
+        Self::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    pub fn new_scale<N: Num>(scale: Size<N>) -> Self {
+        let Size(width, height) = scale.to_f32();
+        Self::new(width, 0.0, 0.0, height, 0.0, 0.0)
+    }
+
+    /// Rotates in the same direction as `Point::rotate_around_zero`.
+    pub fn new_rotation(rotation: f32) -> Self {
+        let cos = rotation.cos();
+        let sin = rotation.sin();
+
+        Self::new(cos, -sin, sin, cos, 0.0, 0.0)
+    }
+
+    /// Returns the 3x3 matrix, in row order.
+    pub fn to_rows(self) -> [[f32; 3]; 3] {
+        [
+            [self.a, self.c, self.tx],
+            [self.b, self.d, self.ty],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+
+    pub fn translation(self) -> Point<f32> {
+        Point(self.tx, self.ty)
+    }
+
+    /// Returns a new matrix which applies this, and then the other.
+    pub fn then(self, other: Self) -> Self {
+        other * self
+    }
+
+    pub fn determinant(self) -> f32 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns `None` when there is no inverse.
+    /// i.e. when everything has been scaled down to zero.
+    pub fn inverse(self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let a = self.d / determinant;
+        let b = -self.b / determinant;
+        let c = -self.c / determinant;
+        let d = self.a / determinant;
+        let tx = -(a * self.tx + c * self.ty);
+        let ty = -(b * self.tx + d * self.ty);
+
+        Some(Self::new(a, b, c, d, tx, ty))
+    }
+
+    fn transform_point_f32(self, Point(x, y): Point<f32>) -> Point<f32> {
+        Point(
+            self.a * x + self.c * y + self.tx,
+            self.b * x + self.d * y + self.ty,
+        )
+    }
+
+    /// How much areas are scaled by, as a single length.
+    ///
+    /// For uniform scales this is exact.
+    /// For non-uniform scales this is the geometric mean of the two.
+    fn length_scale(self) -> f32 {
+        self.determinant().abs().sqrt()
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<N: Num> From<Transform<N>> for Affine2 {
+    fn from(transform: Transform<N>) -> Self {
+        Self::new_translation(transform.position())
+            * Self::new_scale(transform.scale())
+            * Self::new_rotation(transform.rotation())
+    }
+}
+
+impl<N: Num> From<Affine2> for Transform<N> {
+    /// Any skew in the matrix is lost.
+    /// This is exact for matrices made from a position, a rotation,
+    /// and a scale, applied in that order.
+    fn from(matrix: Affine2) -> Self {
+        let scale_width = matrix.a.hypot(matrix.c);
+        let rotation = matrix.c.atan2(matrix.a);
+        let scale_height = if scale_width == 0.0 {
+            matrix.b.hypot(matrix.d)
+        } else {
+            matrix.determinant() / scale_width
+        };
+
+        Transform::new()
+            .set_position(matrix.translation().from_f32())
+            .set_scale(Size(scale_width, scale_height).from_f32())
+            .set_rotation(rotation)
+    }
+}
+
+impl Mul<Affine2> for Affine2 {
+    type Output = Self;
+
+    fn mul(self, other: Affine2) -> Self {
+        Self::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.tx + self.c * other.ty + self.tx,
+            self.b * other.tx + self.d * other.ty + self.ty,
+        )
+    }
+}
+
+impl MulAssign<Affine2> for Affine2 {
+    fn mul_assign(&mut self, other: Affine2) {
+        *self = *self * other;
+    }
+}
+
+impl<N: Num> Mul<Point<N>> for Affine2 {
+    type Output = Point<N>;
+
+    fn mul(self, point: Point<N>) -> Point<N> {
+        self.transform_point_f32(point.to_f32()).from_f32()
+    }
+}
+
+impl<N: Num> Mul<Line<N>> for Affine2 {
+    type Output = Line<N>;
+
+    fn mul(self, line: Line<N>) -> Line<N> {
+        Line(self * line.start(), self * line.end())
+    }
+}
+
+impl<N: Num> Mul<Rect<N>> for Affine2 {
+    type Output = Rect<N>;
+
+    /// Returns the bounding box of the transformed `Rect`.
+    fn mul(self, rect: Rect<N>) -> Rect<N> {
+        let rect_f32 = rect.to_f32();
+        let corners = [
+            rect_f32.bottom_left(),
+            rect_f32.bottom_right(),
+            rect_f32.top_left(),
+            rect_f32.top_right(),
+        ]
+        .map(|corner| self.transform_point_f32(corner));
+
+        let min = corners.iter().fold(corners[0], |min, p| min.min(*p));
+        let max = corners.iter().fold(corners[0], |max, p| max.max(*p));
+
+        min.rect_to(max).from_f32()
+    }
+}
+
+impl<N: Num> Mul<Circle<N>> for Affine2 {
+    type Output = Circle<N>;
+
+    /// Circles can only stay circles under uniform scales.
+    /// For non-uniform scales the radius is scaled by the geometric mean
+    /// of the two scales, so the area of the circle is kept.
+    fn mul(self, circle: Circle<N>) -> Circle<N> {
+        let circle_f32 = circle.to_f32();
+        let centre = self.transform_point_f32(circle_f32.centre());
+        let radius = circle_f32.radius() * self.length_scale();
+
+        Circle(centre, radius).from_f32()
+    }
+}
+
+impl<const N: usize> Mul<BCurve<N>> for Affine2 {
+    type Output = BCurve<N>;
+
+    fn mul(self, curve: BCurve<N>) -> BCurve<N> {
+        BCurve::new_from_points(curve.points.map(|point| self * point))
+    }
+}
+
+impl<N: Num> Mul<Polygon<N>> for Affine2 {
+    type Output = Polygon<N>;
+
+    fn mul(self, polygon: Polygon<N>) -> Polygon<N> {
+        Polygon::new_from_points(
+            polygon
+                .into_points()
+                .into_iter()
+                .map(|point| self * point)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod from_transform {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_transform_points_the_same_as_transform() {
+        let transform = Transform::new()
+            .set_position(Point(1.0, 2.0))
+            .set_scale(Size(2.0, 3.0))
+            .set_rotation(TAU * 0.125);
+        let matrix = Affine2::from(transform);
+        let point = Point(1.0, -2.0);
+
+        assert_approx_point_eq(matrix * point, transform + point);
+    }
+
+    #[test]
+    fn it_should_convert_back_to_the_same_transform() {
+        let transform = Transform::new()
+            .set_position(Point(10.0, 20.0))
+            .set_scale(Size(2.0, -3.0))
+            .set_rotation(TAU * 0.125);
+        let round_trip: Transform<f32> = Affine2::from(transform).into();
+
+        assert_approx_point_eq(round_trip.position(), transform.position());
+        assert_approx_point_eq(round_trip.scale().to_point(), transform.scale().to_point());
+        assert_approx_eq::assert_approx_eq!(round_trip.rotation(), transform.rotation());
+    }
+}
+
+#[cfg(test)]
+mod mul {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_rect_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_apply_right_hand_side_first() {
+        let translate = Affine2::new_translation(Point(10.0, 0.0));
+        let scale = Affine2::new_scale(Size(2.0, 2.0));
+        let point = Point(1.0, 1.0);
+
+        assert_approx_point_eq((translate * scale) * point, Point(12.0, 2.0));
+        assert_approx_point_eq(translate.then(scale) * point, Point(22.0, 2.0));
+    }
+
+    #[test]
+    fn it_should_return_bounding_box_for_rotated_rect() {
+        let rect = Rect(Point(-1.0, -1.0), Size(2.0, 2.0));
+        let rotated = Affine2::new_rotation(TAU * 0.125) * rect;
+        let half_diagonal = 2.0_f32.sqrt();
+
+        assert_approx_rect_eq(
+            rotated,
+            Rect(
+                Point(-half_diagonal, -half_diagonal),
+                Size(half_diagonal * 2.0, half_diagonal * 2.0),
+            ),
+        );
+    }
+
+    #[test]
+    fn it_should_scale_circle_radius() {
+        let circle = Circle(Point(1.0, 2.0), 3.0);
+        let scaled = Affine2::new_scale(Size(2.0, 2.0)) * circle;
+
+        assert_eq!(scaled, Circle(Point(2.0, 4.0), 6.0));
+    }
+
+    #[test]
+    fn it_should_move_every_point_of_a_curve() {
+        let curve = BCurve::new_from_points([Point(0.0, 0.0), Point(1.0, 1.0), Point(2.0, 0.0)]);
+        let moved = Affine2::new_translation(Point(5.0, 5.0)) * curve;
+
+        assert_eq!(
+            moved,
+            BCurve::new_from_points([Point(5.0, 5.0), Point(6.0, 6.0), Point(7.0, 5.0)])
+        );
+    }
+}
+
+#[cfg(test)]
+mod inverse {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_undo_the_matrix() {
+        let matrix = Affine2::new_translation(Point(10.0, 20.0))
+            * Affine2::new_scale(Size(2.0, 4.0))
+            * Affine2::new_rotation(TAU * 0.3);
+        let point = Point(3.0, -8.0);
+        let inverse = matrix.inverse().unwrap();
+
+        assert_approx_point_eq(inverse * (matrix * point), point);
+    }
+
+    #[test]
+    fn it_should_return_none_when_scaled_to_zero() {
+        let matrix = Affine2::new_scale(Size(0.0, 2.0));
+
+        assert_eq!(matrix.inverse(), None);
+    }
+}
+
+#[cfg(test)]
+mod determinant {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_area_scale() {
+        let matrix = Affine2::new_scale(Size(2.0, 3.0)) * Affine2::new_rotation(1.0);
+
+        assert_approx_eq::assert_approx_eq!(matrix.determinant(), 6.0);
+    }
+}
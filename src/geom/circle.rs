@@ -1,3 +1,4 @@
+use std::f32::consts::TAU;
 use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Sub;
@@ -11,6 +12,8 @@ use crate::geom::Line;
 use crate::geom::Point;
 use crate::geom::Size;
 
+use crate::Rng;
+
 mod circle_circumference_points_iterator;
 pub use self::circle_circumference_points_iterator::*;
 
@@ -184,6 +187,16 @@ impl Circle<f32> {
             FromRounded::from_rounded(self.radius()),
         )
     }
+
+    /// Returns a random point within this circle,
+    /// spread evenly across its area.
+    pub fn random_point(&self, rng: &mut Rng) -> Point<f32> {
+        let distance = self.radius() * rng.f32().sqrt();
+        let angle = rng.f32() * TAU;
+        let Point(x, y) = self.centre();
+
+        Point(x + angle.cos() * distance, y + angle.sin() * distance)
+    }
 }
 
 impl<O: Num, N: Num + ToRounded<O>> ToRounded<Circle<O>> for Circle<N> {
@@ -317,3 +330,19 @@ mod overlaps_line {
         assert_eq!(false, circle.overlaps_line(line));
     }
 }
+
+#[cfg(test)]
+mod random_point {
+    use super::*;
+
+    #[test]
+    fn it_should_be_inside() {
+        let circle = Circle(Point(5.0, -5.0), 2.0);
+        let mut rng = Rng::from_seed(7);
+
+        for _ in 0..100 {
+            let point = circle.random_point(&mut rng);
+            assert!(point.hypot_to(circle.centre()) <= 2.0, "{:?}", point);
+        }
+    }
+}
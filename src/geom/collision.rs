@@ -0,0 +1,597 @@
+//! Shape against shape collisions.
+//!
+//! Unlike `overlaps`, these return a `Contact` describing *how* the shapes
+//! overlap. This includes the penetration depth, the contact normal,
+//! and the contact points.
+//!
+//! Shapes which only touch, with no penetration, do not collide.
+//! Polygons are expected to be convex.
+
+use crate::num::Num;
+
+use crate::geom::Circle;
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Polygon;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use crate::geom::vector::closest_point_on_line;
+use crate::geom::vector::dot;
+use crate::geom::vector::length;
+use crate::geom::vector::normalise;
+use crate::geom::vector::perpendicular;
+
+mod contact;
+pub use self::contact::*;
+
+impl<N: Num> Rect<N> {
+    pub fn collide_rect(&self, other: Rect<N>) -> Option<Contact> {
+        let self_f32 = self.to_f32();
+        let other_f32 = other.to_f32();
+        let intersection = self_f32.intersect_rect(other_f32)?;
+        let centre_diff = other_f32.centre() - self_f32.centre();
+
+        let (depth, normal) = if intersection.width() < intersection.height() {
+            let normal = if centre_diff.x() < 0.0 {
+                Size(-1.0, 0.0)
+            } else {
+                Size(1.0, 0.0)
+            };
+
+            (intersection.width(), normal)
+        } else {
+            let normal = if centre_diff.y() < 0.0 {
+                Size(0.0, -1.0)
+            } else {
+                Size(0.0, 1.0)
+            };
+
+            (intersection.height(), normal)
+        };
+
+        if depth <= 0.0 {
+            return None;
+        }
+
+        let points = contact_points(&rect_points(self_f32), &rect_points(other_f32));
+        Some(Contact::new(depth, normal, points))
+    }
+
+    pub fn collide_circle(&self, other: Circle<N>) -> Option<Contact> {
+        circle_contact(&rect_points(self.to_f32()), other.to_f32())
+    }
+
+    /// The contact points are the ends of the line, after it has been
+    /// clipped to the inside of this `Rect`.
+    pub fn collide_line(&self, other: Line<N>) -> Option<Contact> {
+        let self_f32 = self.to_f32();
+        let other_f32 = other.to_f32();
+        let clipped = other_f32.intersect_rect(self_f32)?;
+        let (depth, normal) = separating_axis(&rect_points(self_f32), &line_points(other_f32))?;
+
+        let points = dedup_points(vec![clipped.start(), clipped.end()]);
+        Some(Contact::new(depth, normal, points))
+    }
+
+    pub fn collide_polygon(&self, other: &Polygon<N>) -> Option<Contact> {
+        sat_contact(&rect_points(self.to_f32()), &polygon_points(other))
+    }
+}
+
+impl<N: Num> Circle<N> {
+    pub fn collide_circle(&self, other: Circle<N>) -> Option<Contact> {
+        let self_f32 = self.to_f32();
+        let other_f32 = other.to_f32();
+        let self_radius = self_f32.radius().abs();
+        let other_radius = other_f32.radius().abs();
+
+        let diff = other_f32.centre() - self_f32.centre();
+        let distance = length(diff);
+        let depth = self_radius + other_radius - distance;
+        if depth <= 0.0 {
+            return None;
+        }
+
+        let normal = if distance == 0.0 {
+            Point(0.0, 1.0)
+        } else {
+            diff / distance
+        };
+
+        let point = self_f32.centre() + normal * (self_radius - depth / 2.0);
+        Some(Contact::new(depth, normal.to_size(), vec![point]))
+    }
+
+    pub fn collide_rect(&self, other: Rect<N>) -> Option<Contact> {
+        other.collide_circle(*self).map(Contact::flip)
+    }
+
+    pub fn collide_line(&self, other: Line<N>) -> Option<Contact> {
+        if !self.overlaps_line(other) {
+            return None;
+        }
+
+        circle_contact(&line_points(other.to_f32()), self.to_f32()).map(Contact::flip)
+    }
+
+    pub fn collide_polygon(&self, other: &Polygon<N>) -> Option<Contact> {
+        circle_contact(&polygon_points(other), self.to_f32()).map(Contact::flip)
+    }
+}
+
+impl<N: Num> Line<N> {
+    pub fn collide_rect(&self, other: Rect<N>) -> Option<Contact> {
+        other.collide_line(*self).map(Contact::flip)
+    }
+
+    pub fn collide_circle(&self, other: Circle<N>) -> Option<Contact> {
+        other.collide_line(*self).map(Contact::flip)
+    }
+
+    /// Lines which lie along each other, and so have no single crossing point,
+    /// do not collide.
+    pub fn collide_line(&self, other: Line<N>) -> Option<Contact> {
+        let self_f32 = self.to_f32();
+        let other_f32 = other.to_f32();
+        let point = self_f32.intersect_line(other_f32)?;
+        let (depth, normal) = separating_axis(&line_points(self_f32), &line_points(other_f32))?;
+
+        Some(Contact::new(depth, normal, vec![point]))
+    }
+
+    pub fn collide_polygon(&self, other: &Polygon<N>) -> Option<Contact> {
+        sat_contact(&line_points(self.to_f32()), &polygon_points(other))
+    }
+}
+
+impl<N: Num> Polygon<N> {
+    pub fn collide_polygon(&self, other: &Polygon<N>) -> Option<Contact> {
+        sat_contact(&polygon_points(self), &polygon_points(other))
+    }
+
+    pub fn collide_rect(&self, other: Rect<N>) -> Option<Contact> {
+        other.collide_polygon(self).map(Contact::flip)
+    }
+
+    pub fn collide_circle(&self, other: Circle<N>) -> Option<Contact> {
+        other.collide_polygon(self).map(Contact::flip)
+    }
+
+    pub fn collide_line(&self, other: Line<N>) -> Option<Contact> {
+        other.collide_polygon(self).map(Contact::flip)
+    }
+}
+
+fn rect_points(rect: Rect<f32>) -> Vec<Point<f32>> {
+    vec![
+        rect.bottom_left(),
+        rect.bottom_right(),
+        rect.top_right(),
+        rect.top_left(),
+    ]
+}
+
+fn line_points(line: Line<f32>) -> Vec<Point<f32>> {
+    vec![line.start(), line.end()]
+}
+
+fn polygon_points<N: Num>(polygon: &Polygon<N>) -> Vec<Point<f32>> {
+    polygon
+        .points()
+        .iter()
+        .map(|point| point.to_f32())
+        .collect()
+}
+
+/// Returns the edges of a shape. Two points are treated as a single line.
+fn edges(points: &[Point<f32>]) -> Vec<Line<f32>> {
+    match points.len() {
+        0 | 1 => vec![],
+        2 => vec![Line(points[0], points[1])],
+        len => (0..len)
+            .map(|i| Line(points[i], points[(i + 1) % len]))
+            .collect(),
+    }
+}
+
+fn centre(points: &[Point<f32>]) -> Point<f32> {
+    let total = points
+        .iter()
+        .fold(Point(0.0, 0.0), |total, point| total + *point);
+
+    total / (points.len().max(1) as f32)
+}
+
+fn sat_contact(a: &[Point<f32>], b: &[Point<f32>]) -> Option<Contact> {
+    let (depth, normal) = separating_axis(a, b)?;
+    let points = contact_points(a, b);
+
+    Some(Contact::new(depth, normal, points))
+}
+
+/// Uses the separating axis theorem to find the smallest distance needed
+/// to push `b` out of `a`.
+///
+/// Returns the depth, and the direction to push `b`.
+fn separating_axis(a: &[Point<f32>], b: &[Point<f32>]) -> Option<(f32, Size<f32>)> {
+    let axes = separating_axes(a)
+        .into_iter()
+        .chain(separating_axes(b))
+        .filter(|axis| *axis != Point(0.0, 0.0));
+
+    let mut best: Option<(f32, Point<f32>)> = None;
+    for axis in axes {
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+
+        let push_forwards = a_max - b_min;
+        let push_backwards = b_max - a_min;
+        if push_forwards <= 0.0 || push_backwards <= 0.0 {
+            return None;
+        }
+
+        let (depth, direction) = if push_forwards < push_backwards {
+            (push_forwards, axis)
+        } else {
+            (push_backwards, Point(-axis.x(), -axis.y()))
+        };
+
+        if best.is_none_or(|(best_depth, _)| depth < best_depth) {
+            best = Some((depth, direction));
+        }
+    }
+
+    best.map(|(depth, direction)| (depth, direction.to_size()))
+}
+
+/// The edge normals of the shape.
+///
+/// Lines also include their direction, as their end points act like
+/// very thin edges.
+fn separating_axes(points: &[Point<f32>]) -> Vec<Point<f32>> {
+    let is_line = points.len() == 2;
+
+    edges(points)
+        .into_iter()
+        .flat_map(|edge| {
+            let direction = normalise(edge.end() - edge.start());
+
+            if is_line {
+                vec![perpendicular(direction), direction]
+            } else {
+                vec![perpendicular(direction)]
+            }
+        })
+        .collect()
+}
+
+fn project(points: &[Point<f32>], axis: Point<f32>) -> (f32, f32) {
+    points
+        .iter()
+        .map(|point| dot(*point, axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), n| {
+            (min.min(n), max.max(n))
+        })
+}
+
+/// The contact points are where the edges of the two shapes cross.
+///
+/// When one shape is entirely inside of the other there are no crossings.
+/// Then the points of the inner shape are used instead.
+fn contact_points(a: &[Point<f32>], b: &[Point<f32>]) -> Vec<Point<f32>> {
+    let a_edges = edges(a);
+    let b_edges = edges(b);
+
+    let crossings = a_edges
+        .iter()
+        .flat_map(|a_edge| {
+            b_edges
+                .iter()
+                .filter_map(move |b_edge| a_edge.intersect_line(*b_edge))
+        })
+        .collect::<Vec<_>>();
+
+    if !crossings.is_empty() {
+        return dedup_points(crossings);
+    }
+
+    let b_inside_a = b
+        .iter()
+        .copied()
+        .filter(|point| shape_contains(a, *point))
+        .collect::<Vec<_>>();
+    if !b_inside_a.is_empty() {
+        return b_inside_a;
+    }
+
+    a.iter()
+        .copied()
+        .filter(|point| shape_contains(b, *point))
+        .collect()
+}
+
+fn shape_contains(points: &[Point<f32>], point: Point<f32>) -> bool {
+    points.len() >= 3 && Polygon::new_from_points(points.to_vec()).contains_point(point)
+}
+
+fn dedup_points(points: Vec<Point<f32>>) -> Vec<Point<f32>> {
+    const EPSILON: f32 = 0.0001;
+
+    points.into_iter().fold(Vec::new(), |mut unique, point| {
+        let is_duplicate = unique.iter().any(|other: &Point<f32>| {
+            (other.x() - point.x()).abs() < EPSILON && (other.y() - point.y()).abs() < EPSILON
+        });
+
+        if !is_duplicate {
+            unique.push(point);
+        }
+
+        unique
+    })
+}
+
+/// Collides a circle against a shape.
+/// The normal points from the shape to the circle.
+fn circle_contact(points: &[Point<f32>], circle: Circle<f32>) -> Option<Contact> {
+    let centre = circle.centre();
+    let radius = circle.radius().abs();
+
+    let (closest, closest_edge) = edges(points)
+        .into_iter()
+        .map(|edge| (closest_point_on_line(edge, centre), edge))
+        .min_by(|(a, _), (b, _)| {
+            let a_distance = (*a - centre).to_size().hypot_sqrd();
+            let b_distance = (*b - centre).to_size().hypot_sqrd();
+            a_distance.total_cmp(&b_distance)
+        })?;
+
+    let is_inside = shape_contains(points, centre);
+    let diff = centre - closest;
+    let distance = length(diff);
+
+    let (depth, normal) = if distance == 0.0 {
+        let mut normal = perpendicular(normalise(closest_edge.end() - closest_edge.start()));
+        if dot(normal, closest - self::centre(points)) < 0.0 {
+            normal = Point(-normal.x(), -normal.y());
+        }
+
+        (radius, normal)
+    } else if is_inside {
+        (radius + distance, Point(-diff.x(), -diff.y()) / distance)
+    } else {
+        (radius - distance, diff / distance)
+    };
+
+    if depth <= 0.0 {
+        return None;
+    }
+
+    Some(Contact::new(depth, normal.to_size(), vec![closest]))
+}
+
+#[cfg(test)]
+mod collide_rect {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+
+    #[test]
+    fn it_should_not_collide_with_rect_apart() {
+        let a = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let b = Rect(Point(20.0, 0.0), Size(10.0, 10.0));
+
+        assert_eq!(a.collide_rect(b), None);
+    }
+
+    #[test]
+    fn it_should_not_collide_with_rect_only_touching() {
+        let a = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let b = Rect(Point(10.0, 0.0), Size(10.0, 10.0));
+
+        assert_eq!(a.collide_rect(b), None);
+    }
+
+    #[test]
+    fn it_should_push_out_along_shallowest_axis() {
+        let a = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let b = Rect(Point(8.0, 5.0), Size(10.0, 10.0));
+        let contact = a.collide_rect(b).unwrap();
+
+        assert_eq!(contact.depth(), 2.0);
+        assert_approx_size_eq(contact.normal(), Size(1.0, 0.0));
+        assert_eq!(contact.points(), [Point(10.0, 5.0), Point(8.0, 10.0)]);
+    }
+
+    #[test]
+    fn it_should_point_normal_from_first_to_second() {
+        let a = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let b = Rect(Point(2.0, -7.0), Size(4.0, 10.0));
+        let contact = a.collide_rect(b).unwrap();
+
+        assert_eq!(contact.depth(), 3.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, -1.0));
+    }
+
+    #[test]
+    fn it_should_collide_with_circle() {
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let circle = Circle(Point(12.0, 5.0), 3.0);
+        let contact = rect.collide_circle(circle).unwrap();
+
+        assert_eq!(contact.depth(), 1.0);
+        assert_approx_size_eq(contact.normal(), Size(1.0, 0.0));
+        assert_eq!(contact.points(), [Point(10.0, 5.0)]);
+    }
+
+    #[test]
+    fn it_should_collide_with_circle_inside() {
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let circle = Circle(Point(5.0, 8.0), 1.0);
+        let contact = rect.collide_circle(circle).unwrap();
+
+        assert_eq!(contact.depth(), 3.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, 1.0));
+    }
+
+    #[test]
+    fn it_should_collide_with_line_crossing() {
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let line = Line(Point(-5.0, 2.0), Point(15.0, 2.0));
+        let contact = rect.collide_line(line).unwrap();
+
+        assert_eq!(contact.depth(), 2.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, -1.0));
+        assert_eq!(contact.points(), [Point(0.0, 2.0), Point(10.0, 2.0)]);
+    }
+
+    #[test]
+    fn it_should_not_collide_with_line_outside() {
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let line = Line(Point(-5.0, 12.0), Point(15.0, 12.0));
+
+        assert_eq!(rect.collide_line(line), None);
+    }
+}
+
+#[cfg(test)]
+mod collide_circle {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+
+    #[test]
+    fn it_should_collide_with_overlapping_circle() {
+        let a = Circle(Point(0.0, 0.0), 5.0);
+        let b = Circle(Point(8.0, 0.0), 5.0);
+        let contact = a.collide_circle(b).unwrap();
+
+        assert_eq!(contact.depth(), 2.0);
+        assert_approx_size_eq(contact.normal(), Size(1.0, 0.0));
+        assert_approx_point_eq(contact.points()[0], Point(4.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_not_collide_with_circle_apart() {
+        let a = Circle(Point(0, 0), 2);
+        let b = Circle(Point(5, 5), 2);
+
+        assert_eq!(a.collide_circle(b), None);
+    }
+
+    #[test]
+    fn it_should_collide_with_line() {
+        let circle = Circle(Point(5.0, 3.0), 4.0);
+        let line = Line(Point(0.0, 0.0), Point(10.0, 0.0));
+        let contact = circle.collide_line(line).unwrap();
+
+        assert_eq!(contact.depth(), 1.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, -1.0));
+        assert_eq!(contact.points(), [Point(5.0, 0.0)]);
+    }
+
+    #[test]
+    fn it_should_collide_with_end_of_line() {
+        let circle = Circle(Point(13.0, 0.0), 4.0);
+        let line = Line(Point(0.0, 0.0), Point(10.0, 0.0));
+        let contact = circle.collide_line(line).unwrap();
+
+        assert_eq!(contact.depth(), 1.0);
+        assert_approx_size_eq(contact.normal(), Size(-1.0, 0.0));
+        assert_eq!(contact.points(), [Point(10.0, 0.0)]);
+    }
+
+    #[test]
+    fn it_should_not_collide_with_line_outside() {
+        let circle = Circle(Point(5.0, 5.0), 4.0);
+        let line = Line(Point(0.0, 0.0), Point(10.0, 0.0));
+
+        assert_eq!(circle.collide_line(line), None);
+    }
+
+    #[test]
+    fn it_should_flip_normal_compared_to_rect() {
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let circle = Circle(Point(12.0, 5.0), 3.0);
+
+        assert_eq!(
+            circle.collide_rect(rect),
+            rect.collide_circle(circle).map(Contact::flip)
+        );
+    }
+}
+
+#[cfg(test)]
+mod collide_line {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+
+    #[test]
+    fn it_should_collide_with_crossing_line() {
+        let a = Line(Point(0.0, 0.0), Point(10.0, 0.0));
+        let b = Line(Point(5.0, -1.0), Point(5.0, 4.0));
+        let contact = a.collide_line(b).unwrap();
+
+        assert_eq!(contact.depth(), 1.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, 1.0));
+        assert_eq!(contact.points(), [Point(5.0, 0.0)]);
+    }
+
+    #[test]
+    fn it_should_not_collide_with_parallel_line() {
+        let a = Line(Point(0.0, 0.0), Point(10.0, 0.0));
+        let b = Line(Point(0.0, 1.0), Point(10.0, 1.0));
+
+        assert_eq!(a.collide_line(b), None);
+    }
+}
+
+#[cfg(test)]
+mod collide_polygon {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+
+    fn new_triangle(offset: Point<f32>) -> Polygon<f32> {
+        Polygon::new_from_points(vec![Point(0.0, 0.0), Point(10.0, 0.0), Point(5.0, 10.0)]) + offset
+    }
+
+    #[test]
+    fn it_should_collide_overlapping_polygons() {
+        let a = new_triangle(Point(0.0, 0.0));
+        let b = new_triangle(Point(0.0, 8.0));
+        let contact = a.collide_polygon(&b).unwrap();
+
+        assert_eq!(contact.depth(), 2.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, 1.0));
+        assert_eq!(contact.points().len(), 2);
+    }
+
+    #[test]
+    fn it_should_not_collide_polygons_apart() {
+        let a = new_triangle(Point(0.0, 0.0));
+        let b = new_triangle(Point(20.0, 0.0));
+
+        assert_eq!(a.collide_polygon(&b), None);
+    }
+
+    #[test]
+    fn it_should_match_rect_when_polygon_is_a_rect() {
+        let a = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let b = Rect(Point(8.0, 5.0), Size(10.0, 10.0));
+        let from_polygon = a.collide_polygon(&Polygon::from(b)).unwrap();
+        let from_rect = a.collide_rect(b).unwrap();
+
+        assert_eq!(from_polygon.depth(), from_rect.depth());
+        assert_approx_size_eq(from_polygon.normal(), from_rect.normal());
+    }
+
+    #[test]
+    fn it_should_collide_with_circle() {
+        let polygon = new_triangle(Point(0.0, 0.0));
+        let circle = Circle(Point(5.0, -1.0), 2.0);
+        let contact = polygon.collide_circle(circle).unwrap();
+
+        assert_eq!(contact.depth(), 1.0);
+        assert_approx_size_eq(contact.normal(), Size(0.0, -1.0));
+        assert_eq!(contact.points(), [Point(5.0, 0.0)]);
+    }
+}
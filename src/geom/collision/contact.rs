@@ -0,0 +1,49 @@
+use crate::geom::Point;
+use crate::geom::Size;
+
+/// Describes how two shapes overlap.
+///
+/// The normal points from the first shape, towards the second.
+/// Moving the second shape by `normal * depth` will separate them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contact {
+    depth: f32,
+    normal: Size<f32>,
+    points: Vec<Point<f32>>,
+}
+
+impl Contact {
+    pub fn new(depth: f32, normal: Size<f32>, points: Vec<Point<f32>>) -> Self {
+        Self {
+            depth,
+            normal,
+            points,
+        }
+    }
+
+    /// How far the shapes are overlapping, along the normal.
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// A unit direction, pointing from the first shape to the second.
+    pub fn normal(&self) -> Size<f32> {
+        self.normal
+    }
+
+    /// Where the two shapes are touching.
+    pub fn points(&self) -> &[Point<f32>] {
+        &self.points
+    }
+
+    /// The distance to move the second shape, to separate them.
+    pub fn separation(&self) -> Size<f32> {
+        self.normal * self.depth
+    }
+
+    /// Returns the same contact, but from the perspective of the second shape.
+    pub fn flip(mut self) -> Self {
+        self.normal = self.normal.flip();
+        self
+    }
+}
@@ -32,10 +32,19 @@ use crate::geom::PointPosition;
 use crate::geom::Transform;
 
 use crate::Random;
+use crate::Rng;
 
 mod line_iterator;
 pub use self::line_iterator::LineIterator;
 
+mod line_bresenham_iterator;
+pub use self::line_bresenham_iterator::LineBresenhamIterator;
+
+mod line_cells_touched_iterator;
+pub use self::line_cells_touched_iterator::LineCellsTouchedIterator;
+
+mod line_cell_steps;
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Line<N: Num = f32>(pub Point<N>, pub Point<N>);
 
@@ -439,11 +448,39 @@ where
     }
 }
 
+impl<N: INum + ToSignedClamped> Line<N>
+where
+    <N as ToSignedClamped>::Output: Signed,
+{
+    /// Iterates over the cells from the start to the end inclusive,
+    /// using Bresenham's line algorithm.
+    ///
+    /// This gives the thinnest line, where diagonal steps are allowed.
+    pub fn iter_cells_bresenham(self) -> LineBresenhamIterator<N> {
+        LineBresenhamIterator::new(self)
+    }
+
+    /// Iterates over every cell the line passes through,
+    /// from the start to the end inclusive.
+    ///
+    /// Each step is to a neighbouring cell, with no diagonal steps.
+    /// When the line passes exactly through a corner,
+    /// the cells either side of that corner are both included.
+    pub fn iter_cells_touched(self) -> LineCellsTouchedIterator<N> {
+        LineCellsTouchedIterator::new(self)
+    }
+}
+
 impl Line<f32> {
     pub(crate) fn from_f32<N: Num>(self) -> Line<N> {
         Line(self.start().from_f32(), self.end().from_f32())
     }
 
+    /// Returns a random point along this line.
+    pub fn random_point(self, rng: &mut Rng) -> Point<f32> {
+        self.interpolation_point(rng.f32())
+    }
+
     fn calculate_intersection(self, rect: Rect<f32>, clip_to: PointPosition) -> Option<Point<f32>> {
         let p1 = self.start();
         let slope = self.slope();
@@ -1337,3 +1374,186 @@ mod into_slice {
         assert_eq!(line.into_slice(), [1, 2, 3, 4]);
     }
 }
+
+#[cfg(test)]
+mod iter_cells_bresenham {
+    use super::*;
+
+    #[test]
+    fn it_should_not_overflow_on_long_i8_line() {
+        let cells: Vec<Point<i8>> = Line(Point(0, 0), Point(100, 3))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(cells.len(), 101);
+        assert_eq!(cells.last(), Some(&Point(100, 3)));
+    }
+
+    #[test]
+    fn it_should_not_overflow_on_long_i16_line() {
+        let cells: Vec<Point<i16>> = Line(Point(-10000, -3), Point(10000, 4))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(cells.len(), 20001);
+        assert_eq!(cells.last(), Some(&Point(10000, 4)));
+    }
+
+    #[test]
+    fn it_should_step_along_shallow_line() {
+        let cells: Vec<Point<i32>> = Line(Point(0, 0), Point(4, 2))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(
+            cells,
+            vec![
+                Point(0, 0),
+                Point(1, 1),
+                Point(2, 1),
+                Point(3, 2),
+                Point(4, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_step_backwards() {
+        let cells: Vec<Point<i32>> = Line(Point(0, 0), Point(-2, -2))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(cells, vec![Point(0, 0), Point(-1, -1), Point(-2, -2)]);
+    }
+
+    #[test]
+    fn it_should_step_backwards_with_unsigned_values() {
+        let cells: Vec<Point<usize>> = Line(Point(3, 1), Point(0, 1))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(
+            cells,
+            vec![Point(3, 1), Point(2, 1), Point(1, 1), Point(0, 1)]
+        );
+    }
+
+    #[test]
+    fn it_should_visit_every_row_of_steep_line() {
+        let cells: Vec<Point<usize>> = Line(Point(0, 0), Point(2, 7))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(cells.len(), 8);
+        for (y, cell) in cells.iter().enumerate() {
+            assert_eq!(cell.y(), y);
+        }
+    }
+
+    #[test]
+    fn it_should_return_single_cell_for_empty_line() {
+        let cells: Vec<Point<u32>> = Line(Point(5, 5), Point(5, 5))
+            .iter_cells_bresenham()
+            .collect();
+
+        assert_eq!(cells, vec![Point(5, 5)]);
+    }
+}
+
+#[cfg(test)]
+mod iter_cells_touched {
+    use super::*;
+
+    #[test]
+    fn it_should_not_overflow_on_long_i32_line() {
+        let cells: Vec<Point<i32>> = Line(Point(0, 0), Point(50000, 49999))
+            .iter_cells_touched()
+            .collect();
+
+        assert_eq!(cells.len(), 100000);
+        assert_eq!(cells.last(), Some(&Point(50000, 49999)));
+    }
+
+    #[test]
+    fn it_should_include_every_cell_passed_through() {
+        let cells: Vec<Point<i32>> = Line(Point(0, 0), Point(2, 1))
+            .iter_cells_touched()
+            .collect();
+
+        assert_eq!(
+            cells,
+            vec![Point(0, 0), Point(1, 0), Point(1, 1), Point(2, 1)]
+        );
+    }
+
+    #[test]
+    fn it_should_include_both_cells_at_corners() {
+        let cells: Vec<Point<usize>> = Line(Point(0, 0), Point(2, 2))
+            .iter_cells_touched()
+            .collect();
+
+        assert_eq!(
+            cells,
+            vec![
+                Point(0, 0),
+                Point(1, 0),
+                Point(0, 1),
+                Point(1, 1),
+                Point(2, 1),
+                Point(1, 2),
+                Point(2, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_same_cells_when_reversed() {
+        let line: Line<i32> = Line(Point(-3, 2), Point(4, -1));
+        let forwards: Vec<Point<i32>> = line.iter_cells_touched().collect();
+        let mut backwards: Vec<Point<i32>> = line.flip().iter_cells_touched().collect();
+        backwards.reverse();
+
+        assert_eq!(forwards, backwards);
+    }
+
+    #[test]
+    fn it_should_only_step_to_neighbouring_cells() {
+        let cells: Vec<Point<i32>> = Line(Point(1, 9), Point(-6, 3))
+            .iter_cells_touched()
+            .collect();
+
+        assert_eq!(cells.first(), Some(&Point(1, 9)));
+        assert_eq!(cells.last(), Some(&Point(-6, 3)));
+        for pair in cells.windows(2) {
+            let Point(x, y) = pair[1] - pair[0];
+            assert_eq!(x.abs() + y.abs(), 1, "{:?}", pair);
+        }
+    }
+
+    #[test]
+    fn it_should_return_single_cell_for_empty_line() {
+        let cells: Vec<Point<usize>> = Line(Point(2, 3), Point(2, 3))
+            .iter_cells_touched()
+            .collect();
+
+        assert_eq!(cells, vec![Point(2, 3)]);
+    }
+}
+
+#[cfg(test)]
+mod random_point {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_be_on_the_line() {
+        let line = Line(Point(0.0, 0.0), Point(10.0, 5.0));
+        let mut rng = Rng::from_seed(7);
+
+        for _ in 0..100 {
+            let Point(x, y) = line.random_point(&mut rng);
+            assert!((0.0..=10.0).contains(&x), "{}", x);
+            assert_approx_eq!(y, x / 2.0);
+        }
+    }
+}
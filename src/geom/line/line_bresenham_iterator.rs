@@ -0,0 +1,98 @@
+use num_traits::sign::Signed;
+
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::num::INum;
+use crate::num::NumIdentity;
+use crate::num::ToSignedClamped;
+
+use super::line_cell_steps::abs;
+use super::line_cell_steps::step;
+
+/// Iterates over the cells of a line, using Bresenham's line algorithm.
+///
+/// This is all done in integers, so no cells are skipped or repeated.
+#[derive(Clone, Debug)]
+pub struct LineBresenhamIterator<N: INum + ToSignedClamped> {
+    current: Point<N>,
+    end: Point<N>,
+    dx: <N as ToSignedClamped>::Output,
+    dy: <N as ToSignedClamped>::Output,
+
+    /// Kept centred around zero, and never doubled, so it cannot overflow.
+    error: <N as ToSignedClamped>::Output,
+    half_dx: <N as ToSignedClamped>::Output,
+    half_dy: <N as ToSignedClamped>::Output,
+
+    is_x_positive: bool,
+    is_y_positive: bool,
+    is_done: bool,
+}
+
+impl<N: INum + ToSignedClamped> LineBresenhamIterator<N>
+where
+    <N as ToSignedClamped>::Output: Signed,
+{
+    pub(crate) fn new(line: Line<N>) -> Self {
+        let Line(start, end) = line;
+
+        let one = <N as ToSignedClamped>::Output::one();
+        let two = one + one;
+        let dx = abs(line.x_diff());
+        let dy = abs(line.y_diff());
+        let half_dx = dx / two;
+        let half_dy = dy / two;
+
+        Self {
+            current: start,
+            end,
+            dx,
+            dy,
+            error: (dx - half_dx) - (dy - half_dy),
+            half_dx,
+            half_dy,
+            is_x_positive: start.x() < end.x(),
+            is_y_positive: start.y() < end.y(),
+            is_done: false,
+        }
+    }
+}
+
+impl<N: INum + ToSignedClamped> Iterator for LineBresenhamIterator<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        let current = self.current;
+        if current == self.end {
+            self.is_done = true;
+            return Some(current);
+        }
+
+        let zero = <N as ToSignedClamped>::Output::zero();
+        let is_x_step = self.error >= zero - self.half_dx;
+        let is_y_step = self.error <= self.half_dy;
+
+        if is_x_step && is_y_step {
+            self.error += self.dx - self.dy;
+        } else if is_x_step {
+            self.error -= self.dy;
+        } else {
+            self.error += self.dx;
+        }
+
+        if is_x_step {
+            self.current
+                .set_x(step(self.current.x(), self.is_x_positive));
+        }
+        if is_y_step {
+            self.current
+                .set_y(step(self.current.y(), self.is_y_positive));
+        }
+
+        Some(current)
+    }
+}
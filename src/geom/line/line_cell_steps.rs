@@ -0,0 +1,18 @@
+use crate::num::Num;
+
+/// Moves one cell along, in the direction given.
+pub(crate) fn step<N: Num>(n: N, is_positive: bool) -> N {
+    if is_positive {
+        n + N::one()
+    } else {
+        n - N::one()
+    }
+}
+
+pub(crate) fn abs<N: Num>(n: N) -> N {
+    if n < N::zero() {
+        N::zero() - n
+    } else {
+        n
+    }
+}
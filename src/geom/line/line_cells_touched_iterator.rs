@@ -0,0 +1,127 @@
+use num_traits::sign::Signed;
+
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::num::INum;
+use crate::num::NumIdentity;
+use crate::num::ToSignedClamped;
+
+use super::line_cell_steps::abs;
+use super::line_cell_steps::step;
+
+/// Iterates over every cell a line passes through.
+///
+/// Cells are treated as being centred on each integer position.
+/// When the line passes exactly through the corner of a cell,
+/// both cells either side of that corner are included.
+///
+/// This is all done in integers, so no cells are skipped or repeated.
+#[derive(Clone, Debug)]
+pub struct LineCellsTouchedIterator<N: INum + ToSignedClamped> {
+    current: Point<N>,
+    nx: <N as ToSignedClamped>::Output,
+    ny: <N as ToSignedClamped>::Output,
+    ix: <N as ToSignedClamped>::Output,
+    iy: <N as ToSignedClamped>::Output,
+
+    /// Tracks `ix * ny - iy * nx`, updated as we step, so nothing is multiplied.
+    error: <N as ToSignedClamped>::Output,
+
+    /// Half of `nx - ny`, rounded down, which the error is compared against.
+    /// Doubling the error instead can overflow.
+    half_corner: <N as ToSignedClamped>::Output,
+    is_corner_odd: bool,
+    is_x_positive: bool,
+    is_y_positive: bool,
+    is_started: bool,
+
+    /// Cells to return before moving on.
+    /// These are in reverse order.
+    pending: Vec<Point<N>>,
+}
+
+impl<N: INum + ToSignedClamped> LineCellsTouchedIterator<N>
+where
+    <N as ToSignedClamped>::Output: Signed,
+{
+    pub(crate) fn new(line: Line<N>) -> Self {
+        let Line(start, end) = line;
+        let zero = <N as ToSignedClamped>::Output::zero();
+        let one = <N as ToSignedClamped>::Output::one();
+        let two = one + one;
+        let nx = abs(line.x_diff());
+        let ny = abs(line.y_diff());
+
+        let corner = nx - ny;
+        let mut half_corner = corner / two;
+        if half_corner * two > corner {
+            half_corner -= one;
+        }
+
+        Self {
+            current: start,
+            nx,
+            ny,
+            ix: zero,
+            iy: zero,
+            error: zero,
+            half_corner,
+            is_corner_odd: half_corner * two != corner,
+            is_x_positive: start.x() < end.x(),
+            is_y_positive: start.y() < end.y(),
+            is_started: false,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<N: INum + ToSignedClamped> Iterator for LineCellsTouchedIterator<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pending) = self.pending.pop() {
+            return Some(pending);
+        }
+
+        if !self.is_started {
+            self.is_started = true;
+            return Some(self.current);
+        }
+
+        if self.ix >= self.nx && self.iy >= self.ny {
+            return None;
+        }
+
+        let one = <N as ToSignedClamped>::Output::one();
+
+        // Compares where the line crosses the next vertical and horizontal cell edges.
+        let Point(x, y) = self.current;
+        let next_x = step(x, self.is_x_positive);
+        let next_y = step(y, self.is_y_positive);
+
+        if self.error == self.half_corner && !self.is_corner_odd {
+            // Passes exactly through the corner.
+            self.current = Point(next_x, next_y);
+            self.ix += one;
+            self.iy += one;
+            self.error += self.ny;
+            self.error -= self.nx;
+
+            self.pending.push(self.current);
+            self.pending.push(Point(x, next_y));
+            return Some(Point(next_x, y));
+        }
+
+        if self.error <= self.half_corner {
+            self.current = Point(next_x, y);
+            self.ix += one;
+            self.error += self.ny;
+        } else {
+            self.current = Point(x, next_y);
+            self.iy += one;
+            self.error -= self.nx;
+        }
+
+        Some(self.current)
+    }
+}
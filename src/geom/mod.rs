@@ -1,19 +1,32 @@
+mod affine2;
 mod circle;
+mod collision;
 mod curves;
 mod line;
 mod point;
+mod polygon;
 mod position;
+mod ray;
 mod rect;
 mod size;
+mod spatial_hash;
+mod sweep;
 mod transform;
+pub(crate) mod vector;
 
+pub use self::affine2::*;
 pub use self::circle::*;
+pub use self::collision::*;
 pub use self::curves::*;
 pub use self::line::*;
 pub use self::point::*;
+pub use self::polygon::*;
 pub use self::position::*;
+pub use self::ray::*;
 pub use self::rect::*;
 pub use self::size::*;
+pub use self::spatial_hash::*;
+pub use self::sweep::*;
 pub use self::transform::*;
 
 #[cfg(test)]
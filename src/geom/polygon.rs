@@ -0,0 +1,536 @@
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use crate::num::Num;
+use crate::num::ToRounded;
+
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Transform;
+
+use crate::geom::vector::cross;
+
+mod fill_rule;
+pub use self::fill_rule::*;
+
+mod polygon_edges_iterator;
+pub use self::polygon_edges_iterator::*;
+
+mod winding;
+pub use self::winding::*;
+
+/// A closed shape made up of a list of points.
+///
+/// The last point is joined back to the first,
+/// so there is no need to repeat the first point at the end.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon<N: Num = f32> {
+    points: Vec<Point<N>>,
+}
+
+impl<N: Num> Polygon<N> {
+    pub fn new_from_points(points: Vec<Point<N>>) -> Self {
+        Self { points }
+    }
+
+    pub fn points(&self) -> &[Point<N>] {
+        &self.points
+    }
+
+    pub fn into_points(self) -> Vec<Point<N>> {
+        self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The area of the polygon, using the shoelace formula.
+    ///
+    /// This is positive when the points go counter clockwise,
+    /// and negative when they go clockwise.
+    pub fn signed_area(&self) -> f32 {
+        self.iter_edges_f32()
+            .fold(0.0, |total, (start, end)| total + cross(start, end))
+            / 2.0
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Returns the centre of mass of the polygon.
+    ///
+    /// For polygons with no area (i.e. all of the points are on a line),
+    /// this falls back to the average of the points.
+    pub fn centroid(&self) -> Point<N> {
+        if self.is_empty() {
+            return Point::new_zero_value();
+        }
+
+        let signed_area = self.signed_area();
+        if signed_area == 0.0 {
+            let total = self
+                .points
+                .iter()
+                .fold(Point(0.0, 0.0), |total, point| total + point.to_f32());
+
+            return (total / (self.len() as f32)).from_f32();
+        }
+
+        let total = self
+            .iter_edges_f32()
+            .fold(Point(0.0, 0.0), |total, (start, end)| {
+                total + (start + end) * cross(start, end)
+            });
+
+        (total / (6.0 * signed_area)).from_f32()
+    }
+
+    /// Returns `None` if the polygon has no area,
+    /// as then it has no winding.
+    pub fn winding(&self) -> Option<Winding> {
+        let signed_area = self.signed_area();
+
+        if signed_area > 0.0 {
+            Some(Winding::CounterClockwise)
+        } else if signed_area < 0.0 {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_clockwise(&self) -> bool {
+        self.winding() == Some(Winding::Clockwise)
+    }
+
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.winding() == Some(Winding::CounterClockwise)
+    }
+
+    /// Returns a copy with the points in the opposite order.
+    /// This flips the winding.
+    pub fn reverse(mut self) -> Self {
+        self.points.reverse();
+        self
+    }
+
+    /// Returns a copy where the points are in the winding given.
+    ///
+    /// Polygons with no winding are returned unchanged.
+    pub fn with_winding(self, winding: Winding) -> Self {
+        match self.winding() {
+            Some(current) if current != winding => self.reverse(),
+            _ => self,
+        }
+    }
+
+    /// A polygon is convex when every corner turns in the same direction.
+    ///
+    /// Points which are in a straight line are ignored.
+    /// Anything with fewer than three points is never convex.
+    pub fn is_convex(&self) -> bool {
+        let len = self.len();
+        if len < 3 {
+            return false;
+        }
+
+        let mut winding = None;
+        for i in 0..len {
+            let a = self.points[i].to_f32();
+            let b = self.points[(i + 1) % len].to_f32();
+            let c = self.points[(i + 2) % len].to_f32();
+            let turn = cross(b - a, c - b);
+
+            if turn == 0.0 {
+                continue;
+            }
+
+            let is_left = turn > 0.0;
+            match winding {
+                None => winding = Some(is_left),
+                Some(was_left) if was_left != is_left => return false,
+                _ => {}
+            }
+        }
+
+        winding.is_some()
+    }
+
+    /// Uses the even-odd fill rule.
+    ///
+    /// See `contains_point_with_rule` for other rules.
+    pub fn contains_point(&self, point: Point<N>) -> bool {
+        self.contains_point_with_rule(point, FillRule::EvenOdd)
+    }
+
+    pub fn contains_point_with_rule(&self, point: Point<N>, rule: FillRule) -> bool {
+        match rule {
+            FillRule::EvenOdd => self.crossing_number(point.to_f32()) % 2 == 1,
+            FillRule::NonZero => self.winding_number(point.to_f32()) != 0,
+        }
+    }
+
+    fn crossing_number(&self, point: Point<f32>) -> u32 {
+        self.iter_edges_f32()
+            .filter(|&(start, end)| {
+                if (start.y() > point.y()) == (end.y() > point.y()) {
+                    return false;
+                }
+
+                let cross_x = start.x()
+                    + (point.y() - start.y()) * (end.x() - start.x()) / (end.y() - start.y());
+                point.x() < cross_x
+            })
+            .count() as u32
+    }
+
+    fn winding_number(&self, point: Point<f32>) -> i32 {
+        self.iter_edges_f32()
+            .fold(0, |winding_number, (start, end)| {
+                let side = cross(end - start, point - start);
+
+                if start.y() <= point.y() {
+                    if end.y() > point.y() && side > 0.0 {
+                        return winding_number + 1;
+                    }
+                } else if end.y() <= point.y() && side < 0.0 {
+                    return winding_number - 1;
+                }
+
+                winding_number
+            })
+    }
+
+    pub fn iter_edges(&self) -> PolygonEdgesIterator<'_, N> {
+        PolygonEdgesIterator::new(self)
+    }
+
+    fn iter_edges_f32(&self) -> impl Iterator<Item = (Point<f32>, Point<f32>)> + '_ {
+        self.iter_edges()
+            .map(|line| (line.start().to_f32(), line.end().to_f32()))
+    }
+
+    /// Returns the smallest `Rect` which holds every point.
+    ///
+    /// An empty polygon returns a zero sized `Rect`.
+    pub fn bounding_rect(&self) -> Rect<N> {
+        let first = match self.points.first() {
+            Some(first) => *first,
+            None => return Rect::new_zero_value(),
+        };
+
+        let (min, max) = self
+            .points
+            .iter()
+            .fold((first, first), |(min, max), point| {
+                (min.min(*point), max.max(*point))
+            });
+
+        min.rect_to(max)
+    }
+}
+
+impl<O: Num, N: Num + ToRounded<O>> ToRounded<Polygon<O>> for Polygon<N> {
+    fn to_rounded(self) -> Polygon<O> {
+        Polygon::new_from_points(
+            self.points
+                .into_iter()
+                .map(|point| point.to_rounded())
+                .collect(),
+        )
+    }
+}
+
+impl<N: Num> From<Vec<Point<N>>> for Polygon<N> {
+    fn from(points: Vec<Point<N>>) -> Self {
+        Self::new_from_points(points)
+    }
+}
+
+impl<N: Num> From<Rect<N>> for Polygon<N> {
+    /// The points go counter clockwise, starting from the bottom left.
+    fn from(rect: Rect<N>) -> Self {
+        Self::new_from_points(vec![
+            rect.bottom_left(),
+            rect.bottom_right(),
+            rect.top_right(),
+            rect.top_left(),
+        ])
+    }
+}
+
+impl<N: Num> Add<Point<N>> for Polygon<N> {
+    type Output = Self;
+
+    fn add(mut self, other: Point<N>) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<N: Num> AddAssign<Point<N>> for Polygon<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        for point in &mut self.points {
+            *point += other;
+        }
+    }
+}
+
+impl<N: Num> Sub<Point<N>> for Polygon<N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Point<N>) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<N: Num> SubAssign<Point<N>> for Polygon<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        for point in &mut self.points {
+            *point -= other;
+        }
+    }
+}
+
+impl<N> Add<Transform<N>> for Polygon<N>
+where
+    N: Num,
+{
+    type Output = Polygon<N>;
+
+    #[inline(always)]
+    fn add(self, transform: Transform<N>) -> Self::Output {
+        transform + self
+    }
+}
+
+#[cfg(test)]
+mod signed_area {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_be_positive_when_counter_clockwise() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 20)).into();
+
+        assert_eq!(polygon.signed_area(), 200.0);
+    }
+
+    #[test]
+    fn it_should_be_negative_when_clockwise() {
+        let polygon: Polygon<i32> = Polygon::from(Rect(Point(0, 0), Size(10, 20))).reverse();
+
+        assert_eq!(polygon.signed_area(), -200.0);
+        assert_eq!(polygon.area(), 200.0);
+    }
+
+    #[test]
+    fn it_should_be_zero_for_a_line() {
+        let polygon: Polygon<i32> = Polygon::new_from_points(vec![Point(0, 0), Point(10, 10)]);
+
+        assert_eq!(polygon.signed_area(), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod centroid {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_return_centre_of_rect() {
+        let polygon: Polygon<f32> = Rect(Point(10.0, 20.0), Size(10.0, 20.0)).into();
+
+        assert_approx_point_eq(polygon.centroid(), Point(15.0, 30.0));
+    }
+
+    #[test]
+    fn it_should_return_centre_of_mass_for_triangle() {
+        let polygon: Polygon<f32> =
+            Polygon::new_from_points(vec![Point(0.0, 0.0), Point(9.0, 0.0), Point(0.0, 6.0)]);
+
+        assert_approx_point_eq(polygon.centroid(), Point(3.0, 2.0));
+    }
+
+    #[test]
+    fn it_should_return_average_when_there_is_no_area() {
+        let polygon: Polygon<f32> =
+            Polygon::new_from_points(vec![Point(0.0, 0.0), Point(4.0, 2.0)]);
+
+        assert_approx_point_eq(polygon.centroid(), Point(2.0, 1.0));
+    }
+}
+
+#[cfg(test)]
+mod winding_order {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_detect_counter_clockwise() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 10)).into();
+
+        assert_eq!(polygon.winding(), Some(Winding::CounterClockwise));
+        assert!(polygon.is_counter_clockwise());
+    }
+
+    #[test]
+    fn it_should_detect_clockwise_after_reversing() {
+        let polygon: Polygon<i32> = Polygon::from(Rect(Point(0, 0), Size(10, 10))).reverse();
+
+        assert_eq!(polygon.winding(), Some(Winding::Clockwise));
+        assert!(polygon.is_clockwise());
+    }
+
+    #[test]
+    fn it_should_have_no_winding_with_no_area() {
+        let polygon: Polygon<i32> = Polygon::new_from_points(vec![Point(0, 0), Point(10, 10)]);
+
+        assert_eq!(polygon.winding(), None);
+    }
+
+    #[test]
+    fn it_should_change_winding_when_asked() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 10)).into();
+        let clockwise = polygon.clone().with_winding(Winding::Clockwise);
+
+        assert!(clockwise.is_clockwise());
+        assert_eq!(clockwise.with_winding(Winding::CounterClockwise), polygon);
+    }
+}
+
+#[cfg(test)]
+mod is_convex {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_be_convex_for_rect() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 10)).into();
+
+        assert!(polygon.is_convex());
+        assert!(polygon.reverse().is_convex());
+    }
+
+    #[test]
+    fn it_should_not_be_convex_for_arrow_shape() {
+        let polygon: Polygon<i32> =
+            Polygon::new_from_points(vec![Point(0, 0), Point(10, 5), Point(0, 10), Point(5, 5)]);
+
+        assert!(!polygon.is_convex());
+    }
+
+    #[test]
+    fn it_should_not_be_convex_with_too_few_points() {
+        let polygon: Polygon<i32> = Polygon::new_from_points(vec![Point(0, 0), Point(10, 5)]);
+
+        assert!(!polygon.is_convex());
+    }
+}
+
+#[cfg(test)]
+mod contains_point {
+    use super::*;
+    use crate::geom::Size;
+
+    /// A five pointed star, which crosses over itself.
+    /// The middle pentagon is wound twice.
+    fn new_star() -> Polygon<f32> {
+        Polygon::new_from_points(vec![
+            Point(0.0, 10.0),
+            Point(6.0, -8.0),
+            Point(-9.5, 3.0),
+            Point(9.5, 3.0),
+            Point(-6.0, -8.0),
+        ])
+    }
+
+    #[test]
+    fn it_should_contain_points_inside() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 10)).into();
+
+        assert!(polygon.contains_point(Point(5, 5)));
+    }
+
+    #[test]
+    fn it_should_not_contain_points_outside() {
+        let polygon: Polygon<i32> = Rect(Point(0, 0), Size(10, 10)).into();
+
+        assert!(!polygon.contains_point(Point(15, 5)));
+        assert!(!polygon.contains_point(Point(5, -5)));
+    }
+
+    #[test]
+    fn it_should_not_contain_middle_of_star_with_even_odd() {
+        let star = new_star();
+
+        assert!(!star.contains_point_with_rule(Point(0.0, 0.0), FillRule::EvenOdd));
+        assert!(star.contains_point_with_rule(Point(0.0, 8.0), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn it_should_contain_middle_of_star_with_non_zero() {
+        let star = new_star();
+
+        assert!(star.contains_point_with_rule(Point(0.0, 0.0), FillRule::NonZero));
+        assert!(star.contains_point_with_rule(Point(0.0, 8.0), FillRule::NonZero));
+        assert!(!star.contains_point_with_rule(Point(20.0, 0.0), FillRule::NonZero));
+    }
+}
+
+#[cfg(test)]
+mod bounding_rect {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_surround_all_points() {
+        let polygon: Polygon<i32> =
+            Polygon::new_from_points(vec![Point(3, -2), Point(10, 5), Point(-4, 8)]);
+
+        assert_eq!(polygon.bounding_rect(), Rect(Point(-4, -2), Size(14, 10)));
+    }
+
+    #[test]
+    fn it_should_return_zero_rect_when_empty() {
+        let polygon: Polygon<i32> = Polygon::new_from_points(vec![]);
+
+        assert_eq!(polygon.bounding_rect(), Rect::new_zero_value());
+    }
+}
+
+#[cfg(test)]
+mod add_transform {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_points_vec_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_transform_every_point() {
+        let polygon: Polygon<f32> =
+            Polygon::new_from_points(vec![Point(0.0, 0.0), Point(0.0, 10.0), Point(5.0, 0.0)]);
+        let transform = Transform::new()
+            .set_position(Point(100.0, 200.0))
+            .set_rotation(TAU * 0.25);
+        let transformed = polygon + transform;
+
+        assert_approx_points_vec_eq(
+            transformed.into_points(),
+            vec![
+                Point(100.0, 200.0),
+                Point(110.0, 200.0),
+                Point(100.0, 195.0),
+            ],
+        );
+    }
+}
@@ -0,0 +1,13 @@
+/// How to decide if a point is inside of a shape,
+/// when that shape crosses over itself.
+///
+/// For simple shapes (ones which don't cross over themselves),
+/// both rules give the same answer.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FillRule {
+    /// Inside if a ray from the point crosses the edges an odd number of times.
+    EvenOdd,
+
+    /// Inside if the edges wind around the point at least once.
+    NonZero,
+}
@@ -0,0 +1,64 @@
+use crate::geom::Line;
+use crate::geom::Polygon;
+use crate::num::Num;
+
+/// Iterates over the edges of a `Polygon`.
+///
+/// This includes the closing edge, from the last point back to the first.
+#[derive(Clone, Debug)]
+pub struct PolygonEdgesIterator<'a, N: Num = f32> {
+    polygon: &'a Polygon<N>,
+    index: usize,
+}
+
+impl<'a, N: Num> PolygonEdgesIterator<'a, N> {
+    pub fn new(polygon: &'a Polygon<N>) -> Self {
+        Self { polygon, index: 0 }
+    }
+}
+
+impl<N: Num> Iterator for PolygonEdgesIterator<'_, N> {
+    type Item = Line<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let points = self.polygon.points();
+        if points.len() < 2 || self.index >= points.len() {
+            return None;
+        }
+
+        let start = points[self.index];
+        let end = points[(self.index + 1) % points.len()];
+        self.index += 1;
+
+        Some(Line(start, end))
+    }
+}
+
+#[cfg(test)]
+mod iterator {
+    use super::*;
+    use crate::geom::Point;
+
+    #[test]
+    fn it_should_return_no_edges_for_a_single_point() {
+        let polygon: Polygon<i32> = Polygon::new_from_points(vec![Point(1, 2)]);
+
+        assert_eq!(polygon.iter_edges().count(), 0);
+    }
+
+    #[test]
+    fn it_should_include_the_closing_edge() {
+        let polygon: Polygon<i32> =
+            Polygon::new_from_points(vec![Point(0, 0), Point(10, 0), Point(10, 10)]);
+        let edges: Vec<Line<i32>> = polygon.iter_edges().collect();
+
+        assert_eq!(
+            edges,
+            [
+                Line(Point(0, 0), Point(10, 0)),
+                Line(Point(10, 0), Point(10, 10)),
+                Line(Point(10, 10), Point(0, 0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,18 @@
+/// The order in which the points of a shape are listed.
+///
+/// This uses the same orientation as the rest of `geom`,
+/// where the y axis goes up.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Winding {
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Clockwise => Self::CounterClockwise,
+            Self::CounterClockwise => Self::Clockwise,
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::num::Num;
+
+use crate::geom::BCurve;
+use crate::geom::Circle;
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use crate::geom::vector::cross;
+use crate::geom::vector::dot;
+use crate::geom::vector::normalise;
+use crate::geom::vector::perpendicular;
+
+mod ray_hit;
+pub use self::ray_hit::*;
+
+/// The number of lines a curve is cut into, when casting against it.
+const CURVE_SEGMENTS: u32 = 32;
+
+/// A line which starts at a point, and then goes on forever.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray<N: Num = f32> {
+    origin: Point<N>,
+    direction: Size<f32>,
+}
+
+impl<N: Num> Ray<N> {
+    /// The direction is normalised, so it doesn't need to be of length 1.
+    pub fn new(origin: Point<N>, direction: Size<f32>) -> Self {
+        Self {
+            origin,
+            direction: normalise(direction.to_point()).to_size(),
+        }
+    }
+
+    pub fn new_from_angle(origin: Point<N>, angle: f32) -> Self {
+        Self::new(origin, Size(angle.cos(), angle.sin()))
+    }
+
+    pub fn origin(&self) -> Point<N> {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Size<f32> {
+        self.direction
+    }
+
+    /// Returns the point which is the distance given along the ray.
+    pub fn point_at(&self, distance: f32) -> Point<f32> {
+        self.origin.to_f32() + self.direction * distance
+    }
+
+    /// Returns the part of the ray, from its origin, up to the distance given.
+    pub fn to_line(&self, distance: f32) -> Line<N> {
+        Line(self.origin, self.point_at(distance).from_f32())
+    }
+
+    pub fn cast_against_line(&self, line: Line<N>) -> Option<RayHit> {
+        self.cast_against_line_f32(line.to_f32())
+    }
+
+    /// If the ray starts inside the `Rect`,
+    /// then this returns where it leaves.
+    pub fn cast_against_rect(&self, rect: Rect<N>) -> Option<RayHit> {
+        let rect_f32 = rect.to_f32();
+
+        [
+            rect_f32.bottom_edge(),
+            rect_f32.right_edge(),
+            rect_f32.top_edge(),
+            rect_f32.left_edge(),
+        ]
+        .into_iter()
+        .filter_map(|edge| self.cast_against_line_f32(edge))
+        .min_by(|a, b| a.distance().total_cmp(&b.distance()))
+    }
+
+    /// If the ray starts inside the `Circle`,
+    /// then this returns where it leaves.
+    ///
+    /// A ray with no direction never hits.
+    pub fn cast_against_circle(&self, circle: Circle<N>) -> Option<RayHit> {
+        let circle_f32 = circle.to_f32();
+        let radius = circle_f32.radius().abs();
+        let direction = self.direction.to_point();
+        if direction == Point(0.0, 0.0) {
+            return None;
+        }
+        let offset = self.origin.to_f32() - circle_f32.centre();
+
+        let b = dot(offset, direction);
+        let c = dot(offset, offset) - radius * radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let is_inside = c < 0.0;
+        let distance = if is_inside {
+            -b + discriminant.sqrt()
+        } else {
+            -b - discriminant.sqrt()
+        };
+
+        if distance < 0.0 {
+            return None;
+        }
+
+        let point = self.point_at(distance);
+        let mut normal = normalise(point - circle_f32.centre());
+        if is_inside {
+            normal = -normal;
+        }
+
+        Some(RayHit::new(distance, point, normal.to_size()))
+    }
+
+    /// The curve is approximated using a series of lines.
+    pub fn cast_against_bcurve<const M: usize>(&self, curve: &BCurve<M>) -> Option<RayHit> {
+        self.cast_against_bcurve_with_segments(curve, CURVE_SEGMENTS)
+    }
+
+    /// The curve is cut into the number of lines given.
+    /// More lines will be more accurate, but slower.
+    pub fn cast_against_bcurve_with_segments<const M: usize>(
+        &self,
+        curve: &BCurve<M>,
+        num_segments: u32,
+    ) -> Option<RayHit> {
+        curve
+            .iter_interpolation_lines(num_segments)
+            .filter_map(|line| self.cast_against_line_f32(line))
+            .min_by(|a, b| a.distance().total_cmp(&b.distance()))
+    }
+
+    fn cast_against_line_f32(&self, line: Line<f32>) -> Option<RayHit> {
+        let direction = self.direction.to_point();
+        let line_diff = line.end() - line.start();
+
+        let denominator = cross(direction, line_diff);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let start_diff = line.start() - self.origin.to_f32();
+        let distance = cross(start_diff, line_diff) / denominator;
+        let line_n = cross(start_diff, direction) / denominator;
+        if distance < 0.0 || !(0.0..=1.0).contains(&line_n) {
+            return None;
+        }
+
+        let mut normal = normalise(perpendicular(line_diff));
+        if dot(normal, direction) > 0.0 {
+            normal = -normal;
+        }
+
+        let point = line.start() + line_diff * line_n;
+        Some(RayHit::new(distance, point, normal.to_size()))
+    }
+}
+
+impl<N: Num> From<Line<N>> for Ray<N> {
+    /// Starts at the start of the line, and heads towards the end.
+    fn from(line: Line<N>) -> Self {
+        let line_f32 = line.to_f32();
+        let diff = line_f32.end() - line_f32.start();
+
+        Self::new(line.start(), diff.to_size())
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+
+    #[test]
+    fn it_should_normalise_the_direction() {
+        let ray: Ray<i32> = Ray::new(Point(1, 2), Size(3.0, 4.0));
+
+        assert_approx_size_eq(ray.direction(), Size(0.6, 0.8));
+    }
+}
+
+#[cfg(test)]
+mod cast_against_line {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_line_in_front() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(1.0, 0.0));
+        let line = Line(Point(10.0, -5.0), Point(10.0, 5.0));
+        let hit = ray.cast_against_line(line).unwrap();
+
+        assert_approx_eq!(hit.distance(), 10.0);
+        assert_approx_point_eq(hit.point(), Point(10.0, 0.0));
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_measure_distance_along_diagonal() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(1.0, 1.0));
+        let line = Line(Point(10.0, -50.0), Point(10.0, 50.0));
+        let hit = ray.cast_against_line(line).unwrap();
+
+        assert_approx_eq!(hit.distance(), 200.0_f32.sqrt());
+    }
+
+    #[test]
+    fn it_should_not_hit_line_behind() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(-1.0, 0.0));
+        let line = Line(Point(10.0, -5.0), Point(10.0, 5.0));
+
+        assert_eq!(ray.cast_against_line(line), None);
+    }
+
+    #[test]
+    fn it_should_not_hit_parallel_line() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(1.0, 0.0));
+        let line = Line(Point(0.0, 5.0), Point(10.0, 5.0));
+
+        assert_eq!(ray.cast_against_line(line), None);
+    }
+}
+
+#[cfg(test)]
+mod cast_against_rect {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_nearest_side() {
+        let ray = Ray::new(Point(5.0, 20.0), Size(0.0, -1.0));
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let hit = ray.cast_against_rect(rect).unwrap();
+
+        assert_approx_eq!(hit.distance(), 10.0);
+        assert_approx_point_eq(hit.point(), Point(5.0, 10.0));
+        assert_approx_size_eq(hit.normal(), Size(0.0, 1.0));
+    }
+
+    #[test]
+    fn it_should_hit_exit_when_starting_inside() {
+        let ray = Ray::new(Point(5.0, 5.0), Size(1.0, 0.0));
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+        let hit = ray.cast_against_rect(rect).unwrap();
+
+        assert_approx_eq!(hit.distance(), 5.0);
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_miss_rect_to_the_side() {
+        let ray = Ray::new(Point(20.0, 20.0), Size(0.0, -1.0));
+        let rect = Rect(Point(0.0, 0.0), Size(10.0, 10.0));
+
+        assert_eq!(ray.cast_against_rect(rect), None);
+    }
+}
+
+#[cfg(test)]
+mod cast_against_circle {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_circle_in_front() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(1.0, 0.0));
+        let circle = Circle(Point(10.0, 0.0), 2.0);
+        let hit = ray.cast_against_circle(circle).unwrap();
+
+        assert_approx_eq!(hit.distance(), 8.0);
+        assert_approx_point_eq(hit.point(), Point(8.0, 0.0));
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_hit_exit_when_starting_inside() {
+        let ray = Ray::new(Point(10.0, 0.0), Size(0.0, 1.0));
+        let circle = Circle(Point(10.0, 0.0), 2.0);
+        let hit = ray.cast_against_circle(circle).unwrap();
+
+        assert_approx_eq!(hit.distance(), 2.0);
+        assert_approx_size_eq(hit.normal(), Size(0.0, -1.0));
+    }
+
+    #[test]
+    fn it_should_miss_circle_behind() {
+        let ray = Ray::new(Point(0.0, 0.0), Size(-1.0, 0.0));
+        let circle = Circle(Point(10.0, 0.0), 2.0);
+
+        assert_eq!(ray.cast_against_circle(circle), None);
+    }
+
+    #[test]
+    fn it_should_miss_with_zero_direction() {
+        let ray = Ray::new(Point(10.0, 0.0), Size(0.0, 0.0));
+        let circle = Circle(Point(10.0, 0.0), 2.0);
+
+        assert_eq!(ray.cast_against_circle(circle), None);
+    }
+}
+
+#[cfg(test)]
+mod cast_against_bcurve {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_curve() {
+        let ray = Ray::new(Point(5.0, -10.0), Size(0.0, 1.0));
+        let curve = BCurve::new_from_points([Point(0.0, 0.0), Point(5.0, 10.0), Point(10.0, 0.0)]);
+        let hit = ray.cast_against_bcurve(&curve).unwrap();
+
+        assert_approx_eq!(hit.distance(), 15.0, 0.01);
+        assert_approx_eq!(hit.point().y(), 5.0, 0.01);
+    }
+
+    #[test]
+    fn it_should_miss_curve_to_the_side() {
+        let ray = Ray::new(Point(20.0, -10.0), Size(0.0, 1.0));
+        let curve = BCurve::new_from_points([Point(0.0, 0.0), Point(5.0, 10.0), Point(10.0, 0.0)]);
+
+        assert_eq!(ray.cast_against_bcurve(&curve), None);
+    }
+}
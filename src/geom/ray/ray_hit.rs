@@ -0,0 +1,34 @@
+use crate::geom::Point;
+use crate::geom::Size;
+
+/// Where a `Ray` hits a shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit {
+    distance: f32,
+    point: Point<f32>,
+    normal: Size<f32>,
+}
+
+impl RayHit {
+    pub fn new(distance: f32, point: Point<f32>, normal: Size<f32>) -> Self {
+        Self {
+            distance,
+            point,
+            normal,
+        }
+    }
+
+    /// How far along the ray the hit is, from its origin.
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn point(&self) -> Point<f32> {
+        self.point
+    }
+
+    /// The direction of the surface hit, facing back towards the ray.
+    pub fn normal(&self) -> Size<f32> {
+        self.normal
+    }
+}
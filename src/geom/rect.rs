@@ -27,6 +27,8 @@ use crate::geom::VerticalPosition;
 use crate::internal::macros::quick_n_div;
 use crate::internal::macros::quick_n_mul;
 
+use crate::Rng;
+
 mod rect_iterator;
 pub use self::rect_iterator::RectIterator;
 
@@ -365,6 +367,16 @@ impl Rect<f32> {
     pub(crate) fn from_f32<N: Num>(self) -> Rect<N> {
         Rect(self.bottom_left().from_f32(), self.size().from_f32())
     }
+
+    /// Returns a random point within this rectangle.
+    pub fn random_point(&self, rng: &mut Rng) -> Point<f32> {
+        let Point(x, y) = self.bottom_left();
+
+        Point(
+            x + rng.f32_range(0.0..self.width()),
+            y + rng.f32_range(0.0..self.height()),
+        )
+    }
 }
 
 impl<O: Num, N: Num + ToRounded<O>> ToRounded<Rect<O>> for Rect<N> {
@@ -1089,3 +1101,19 @@ mod div_n {
         assert_eq!(doubled, Rect::new_from_centre(Point(10, 12), Size(6, 8)));
     }
 }
+
+#[cfg(test)]
+mod random_point {
+    use super::*;
+
+    #[test]
+    fn it_should_be_inside() {
+        let rect = Rect(Point(-2.0, 3.0), Size(4.0, 1.0));
+        let mut rng = Rng::from_seed(7);
+
+        for _ in 0..100 {
+            let point = rect.random_point(&mut rng);
+            assert!(rect.contains_point(point), "{:?}", point);
+        }
+    }
+}
@@ -0,0 +1,681 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use num_traits::ToPrimitive;
+
+use crate::num::Num;
+
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+mod spatial_hash_key;
+pub use self::spatial_hash_key::*;
+
+type Cell = (i32, i32);
+
+#[derive(Clone, Debug)]
+struct SpatialHashEntry<N: Num + ToPrimitive, T> {
+    rect: Rect<N>,
+    value: T,
+}
+
+/// Stores items by their bounds, in a uniform grid of cells.
+///
+/// Items are placed into every cell their `Rect` covers.
+/// Queries then only need to look at the items in the cells they touch,
+/// rather than at every item.
+///
+/// Pick a cell size around the size of a typical item.
+#[derive(Clone, Debug)]
+pub struct SpatialHash<T, N: Num + ToPrimitive = f32> {
+    cell_size: f32,
+    next_id: usize,
+    entries: HashMap<SpatialHashKey, SpatialHashEntry<N, T>>,
+    cells: HashMap<Cell, Vec<SpatialHashKey>>,
+}
+
+impl<T, N: Num + ToPrimitive> SpatialHash<T, N> {
+    pub fn new(cell_size: N) -> Self {
+        let cell_size = to_f32(cell_size).abs();
+        assert!(cell_size > 0.0, "SpatialHash cell size must be above zero");
+
+        Self {
+            cell_size,
+            next_id: 0,
+            entries: HashMap::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cells.clear();
+    }
+
+    pub fn insert(&mut self, rect: Rect<N>, value: T) -> SpatialHashKey {
+        let key = SpatialHashKey::new(self.next_id);
+        self.next_id += 1;
+
+        self.add_to_cells(key, rect);
+        self.entries.insert(key, SpatialHashEntry { rect, value });
+
+        key
+    }
+
+    /// Returns the value removed, or `None` if the key is not present.
+    pub fn remove(&mut self, key: SpatialHashKey) -> Option<T> {
+        let entry = self.entries.remove(&key)?;
+        self.remove_from_cells(key, entry.rect);
+
+        Some(entry.value)
+    }
+
+    /// Moves the item to the new bounds.
+    ///
+    /// Returns false if the key is not present.
+    pub fn update(&mut self, key: SpatialHashKey, rect: Rect<N>) -> bool {
+        let old_rect = match self.entries.get_mut(&key) {
+            Some(entry) => std::mem::replace(&mut entry.rect, rect),
+            None => return false,
+        };
+
+        self.remove_from_cells(key, old_rect);
+        self.add_to_cells(key, rect);
+
+        true
+    }
+
+    pub fn get(&self, key: SpatialHashKey) -> Option<&T> {
+        self.entries.get(&key).map(|entry| &entry.value)
+    }
+
+    pub fn get_mut(&mut self, key: SpatialHashKey) -> Option<&mut T> {
+        self.entries.get_mut(&key).map(|entry| &mut entry.value)
+    }
+
+    pub fn get_rect(&self, key: SpatialHashKey) -> Option<Rect<N>> {
+        self.entries.get(&key).map(|entry| entry.rect)
+    }
+
+    /// Returns all items whose bounds overlap the `Rect` given.
+    ///
+    /// This uses `Rect::overlaps`, so items which only touch are not included.
+    pub fn query_rect(&self, rect: Rect<N>) -> Vec<(SpatialHashKey, &T)> {
+        let range = self.cell_range(rect);
+
+        // Large areas are quicker to check against the cells in use.
+        if range_len(range) > self.cells.len() as u64 {
+            self.query_cells(self.cells_in_use_within(range), |item_rect| {
+                item_rect.overlaps(rect)
+            })
+        } else {
+            self.query_cells(cells_within(range), |item_rect| item_rect.overlaps(rect))
+        }
+    }
+
+    /// Returns all items whose bounds contain the point given.
+    pub fn query_point(&self, point: Point<N>) -> Vec<(SpatialHashKey, &T)> {
+        let range = self.cell_range(Rect(point, Size::new_zero_value()));
+
+        self.query_cells(cells_within(range), |item_rect| {
+            item_rect.contains_point(point)
+        })
+    }
+
+    /// Returns all items whose bounds the line crosses.
+    ///
+    /// Only the cells the line passes through are looked at.
+    pub fn query_line(&self, line: Line<N>) -> Vec<(SpatialHashKey, &T)> {
+        let line_f32 = Line(point_to_f32(line.start()), point_to_f32(line.end()));
+        let (start_x, start_y) = self.cell_for_point(line_f32.start());
+        let (end_x, end_y) = self.cell_for_point(line_f32.end());
+        let num_cells = (end_x as i64 - start_x as i64).unsigned_abs()
+            + (end_y as i64 - start_y as i64).unsigned_abs()
+            + 1;
+
+        // Long lines are quicker to check against the cells in use.
+        if num_cells > self.cells.len() as u64 {
+            self.query_cells(self.cells.keys().copied(), |item_rect| {
+                line.intersect_rect(item_rect).is_some()
+            })
+        } else {
+            self.query_cells(self.cells_on_line(line_f32), |item_rect| {
+                line.intersect_rect(item_rect).is_some()
+            })
+        }
+    }
+
+    /// Returns the `k` items whose centres are nearest the point given,
+    /// in order of nearest first.
+    pub fn query_nearest(&self, point: Point<N>, k: usize) -> Vec<(SpatialHashKey, &T)> {
+        if k == 0 || self.is_empty() {
+            return Vec::new();
+        }
+
+        let point_f32 = point_to_f32(point);
+        let (centre_x, centre_y) = self.cell_for_point(point_f32);
+        let max_ring = self.max_ring_from((centre_x, centre_y));
+
+        let mut seen = HashSet::new();
+        let mut found: Vec<(f32, SpatialHashKey)> = Vec::new();
+
+        for ring in 0..=max_ring {
+            // Once the rings cover more cells than are in use,
+            // it's quicker to look at every entry left.
+            let ring_width = ring * 2 + 1;
+            if ring_width * ring_width > self.cells.len() as i64 {
+                for (key, entry) in &self.entries {
+                    if seen.insert(*key) {
+                        let centre = rect_to_f32(entry.rect).centre();
+                        found.push((point_f32.hypot_to(centre), *key));
+                    }
+                }
+                break;
+            }
+
+            for cell in ring_cells((centre_x, centre_y), ring) {
+                let Some(keys) = self.cells.get(&cell) else {
+                    continue;
+                };
+
+                for key in keys {
+                    if seen.insert(*key) {
+                        let centre = rect_to_f32(self.entries[key].rect).centre();
+                        let distance = point_f32.hypot_to(centre);
+                        found.push((distance, *key));
+                    }
+                }
+            }
+
+            if seen.len() == self.entries.len() {
+                break;
+            }
+
+            // Anything in a later ring is at least this far away.
+            let ring_distance = ring as f32 * self.cell_size;
+            if found.len() >= k {
+                found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+                if found[k - 1].0 <= ring_distance {
+                    break;
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        found
+            .into_iter()
+            .take(k)
+            .map(|(_, key)| (key, &self.entries[&key].value))
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SpatialHashKey, Rect<N>, &T)> {
+        self.entries
+            .iter()
+            .map(|(key, entry)| (*key, entry.rect, &entry.value))
+    }
+
+    fn query_cells<I, F>(&self, cells: I, mut is_match: F) -> Vec<(SpatialHashKey, &T)>
+    where
+        I: Iterator<Item = Cell>,
+        F: FnMut(Rect<N>) -> bool,
+    {
+        let mut keys: Vec<SpatialHashKey> = cells
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|key| is_match(self.entries[key].rect))
+            .collect();
+        keys.sort();
+
+        keys.into_iter()
+            .map(|key| (key, &self.entries[&key].value))
+            .collect()
+    }
+
+    fn add_to_cells(&mut self, key: SpatialHashKey, rect: Rect<N>) {
+        for cell in cells_within(self.cell_range(rect)) {
+            self.cells.entry(cell).or_default().push(key);
+        }
+    }
+
+    fn remove_from_cells(&mut self, key: SpatialHashKey, rect: Rect<N>) {
+        let range = self.cell_range(rect);
+
+        if range_len(range) > self.cells.len() as u64 {
+            let cells: Vec<Cell> = self.cells_in_use_within(range).collect();
+            for cell in cells {
+                self.remove_from_cell(key, cell);
+            }
+        } else {
+            for cell in cells_within(range) {
+                self.remove_from_cell(key, cell);
+            }
+        }
+    }
+
+    fn remove_from_cell(&mut self, key: SpatialHashKey, cell: Cell) {
+        if let Some(keys) = self.cells.get_mut(&cell) {
+            keys.retain(|other| *other != key);
+
+            if keys.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    /// Returns the bottom left and top right cells the rect covers, inclusive.
+    fn cell_range(&self, rect: Rect<N>) -> (Cell, Cell) {
+        let rect_f32 = rect_to_f32(rect);
+        let min = rect_f32.bottom_left().min(rect_f32.top_right());
+        let max = rect_f32.bottom_left().max(rect_f32.top_right());
+
+        (self.cell_for_point(min), self.cell_for_point(max))
+    }
+
+    fn cells_in_use_within(&self, range: (Cell, Cell)) -> impl Iterator<Item = Cell> + '_ {
+        let ((min_x, min_y), (max_x, max_y)) = range;
+
+        self.cells
+            .keys()
+            .copied()
+            .filter(move |(x, y)| (min_x..=max_x).contains(x) && (min_y..=max_y).contains(y))
+    }
+
+    /// Walks along the line one column of cells at a time,
+    /// returning the cells in each column which the line passes through.
+    fn cells_on_line(&self, Line(start, end): Line<f32>) -> impl Iterator<Item = Cell> {
+        let (start, end) = if start.x() <= end.x() {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        let cell_size = self.cell_size;
+        let (min_x, _) = self.cell_for_point(start);
+        let (max_x, _) = self.cell_for_point(end);
+        let y_at = move |x: f32| {
+            let amount = (x - start.x()) / (end.x() - start.x());
+            start.y() + (end.y() - start.y()) * amount
+        };
+
+        (min_x..=max_x).flat_map(move |x| {
+            let from_y = if x == min_x {
+                start.y()
+            } else {
+                y_at(x as f32 * cell_size)
+            };
+            let to_y = if x == max_x {
+                end.y()
+            } else {
+                y_at((x + 1) as f32 * cell_size)
+            };
+
+            let min_y = (from_y.min(to_y) / cell_size).floor() as i32;
+            let max_y = (from_y.max(to_y) / cell_size).floor() as i32;
+            (min_y..=max_y).map(move |y| (x, y))
+        })
+    }
+
+    fn cell_for_point(&self, Point(x, y): Point<f32>) -> Cell {
+        (
+            (x / self.cell_size).floor() as i32,
+            (y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// How many rings out from the cell given are needed to reach every cell in use.
+    ///
+    /// This is an i64, as far cells can be more than an i32 apart.
+    fn max_ring_from(&self, (x, y): Cell) -> i64 {
+        self.cells
+            .keys()
+            .map(|(cell_x, cell_y)| {
+                let distance_x = (*cell_x as i64 - x as i64).abs();
+                let distance_y = (*cell_y as i64 - y as i64).abs();
+
+                distance_x.max(distance_y)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Returns every cell from the bottom left to the top right, inclusive.
+fn cells_within(((min_x, min_y), (max_x, max_y)): (Cell, Cell)) -> impl Iterator<Item = Cell> {
+    (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| (x, y)))
+}
+
+/// How many cells there are from the bottom left to the top right, inclusive.
+fn range_len(((min_x, min_y), (max_x, max_y)): (Cell, Cell)) -> u64 {
+    let width = (max_x as i64 - min_x as i64 + 1).max(0) as u64;
+    let height = (max_y as i64 - min_y as i64 + 1).max(0) as u64;
+
+    width.saturating_mul(height)
+}
+
+/// Converts to an f32, keeping any fraction.
+///
+/// `ToRounded` is not used, as it rounds an f64 to a whole number.
+fn to_f32<N: ToPrimitive>(n: N) -> f32 {
+    n.to_f64().unwrap_or(f64::NAN) as f32
+}
+
+fn point_to_f32<N: Num + ToPrimitive>(Point(x, y): Point<N>) -> Point<f32> {
+    Point(to_f32(x), to_f32(y))
+}
+
+fn rect_to_f32<N: Num + ToPrimitive>(Rect(bottom_left, Size(width, height)): Rect<N>) -> Rect<f32> {
+    Rect(
+        point_to_f32(bottom_left),
+        Size(to_f32(width), to_f32(height)),
+    )
+}
+
+/// Returns the cells which are exactly `ring` cells away from the centre.
+///
+/// Cells beyond the range of an i32 are skipped, as nothing can be stored there.
+fn ring_cells((x, y): Cell, ring: i64) -> Vec<Cell> {
+    let (x, y) = (x as i64, y as i64);
+    if ring == 0 {
+        return vec![(x as i32, y as i32)];
+    }
+
+    let mut cells = Vec::with_capacity((ring * 8) as usize);
+    for offset in -ring..=ring {
+        cells.push((x + offset, y - ring));
+        cells.push((x + offset, y + ring));
+    }
+    for offset in (-ring + 1)..ring {
+        cells.push((x - ring, y + offset));
+        cells.push((x + ring, y + offset));
+    }
+
+    cells
+        .into_iter()
+        .filter_map(|(x, y)| Some((i32::try_from(x).ok()?, i32::try_from(y).ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod insert {
+    use super::*;
+
+    #[test]
+    fn it_should_store_value_and_rect() {
+        let mut hash = SpatialHash::new(10.0);
+        let rect = Rect(Point(1.0, 2.0), Size(3.0, 4.0));
+        let key = hash.insert(rect, "a");
+
+        assert_eq!(hash.len(), 1);
+        assert_eq!(hash.get(key), Some(&"a"));
+        assert_eq!(hash.get_rect(key), Some(rect));
+    }
+
+    #[test]
+    fn it_should_allow_fractional_f64_cell_sizes() {
+        let mut hash: SpatialHash<&str, f64> = SpatialHash::new(0.25);
+        let a = hash.insert(Rect(Point(0.1, 0.1), Size(0.1, 0.1)), "a");
+        let b = hash.insert(Rect(Point(0.6, 0.1), Size(0.1, 0.1)), "b");
+
+        assert_eq!(hash.query_point(Point(0.15, 0.15)), vec![(a, &"a")]);
+        assert_eq!(hash.query_point(Point(0.65, 0.15)), vec![(b, &"b")]);
+        assert_eq!(hash.query_nearest(Point(0.5, 0.1), 1), vec![(b, &"b")]);
+    }
+
+    #[test]
+    fn it_should_keep_half_f64_cell_sizes() {
+        let mut hash: SpatialHash<&str, f64> = SpatialHash::new(0.5);
+        let key = hash.insert(Rect(Point(0.6, 0.6), Size(0.2, 0.2)), "a");
+
+        assert_eq!(hash.cell_size, 0.5);
+        assert_eq!(hash.cells.keys().collect::<Vec<_>>(), vec![&(1, 1)]);
+        assert_eq!(hash.query_point(Point(0.7, 0.7)), vec![(key, &"a")]);
+    }
+
+    #[test]
+    fn it_should_work_with_integers() {
+        let mut hash: SpatialHash<&str, i32> = SpatialHash::new(10);
+        let key = hash.insert(Rect(Point(-15, -15), Size(30, 30)), "a");
+
+        assert_eq!(hash.query_point(Point(0, 0)), vec![(key, &"a")]);
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::*;
+
+    #[test]
+    fn it_should_remove_value() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(0.0, 0.0), Size(25.0, 25.0)), "a");
+
+        assert_eq!(hash.remove(key), Some("a"));
+        assert!(hash.is_empty());
+        assert_eq!(hash.query_point(Point(5.0, 5.0)), vec![]);
+    }
+
+    #[test]
+    fn it_should_return_none_when_removed_twice() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(0.0, 0.0), Size(5.0, 5.0)), "a");
+        hash.remove(key);
+
+        assert_eq!(hash.remove(key), None);
+    }
+}
+
+#[cfg(test)]
+mod update {
+    use super::*;
+
+    #[test]
+    fn it_should_move_item_to_new_cells() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(0.0, 0.0), Size(5.0, 5.0)), "a");
+
+        assert!(hash.update(key, Rect(Point(100.0, 100.0), Size(5.0, 5.0))));
+        assert_eq!(hash.query_point(Point(2.0, 2.0)), vec![]);
+        assert_eq!(hash.query_point(Point(102.0, 102.0)), vec![(key, &"a")]);
+    }
+
+    #[test]
+    fn it_should_return_false_for_missing_key() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(0.0, 0.0), Size(5.0, 5.0)), "a");
+        hash.remove(key);
+
+        assert!(!hash.update(key, Rect(Point(1.0, 1.0), Size(5.0, 5.0))));
+    }
+}
+
+#[cfg(test)]
+mod query_rect {
+    use super::*;
+
+    #[test]
+    fn it_should_return_overlapping_items_once() {
+        let mut hash = SpatialHash::new(10.0);
+        let big = hash.insert(Rect(Point(0.0, 0.0), Size(50.0, 50.0)), "big");
+        let small = hash.insert(Rect(Point(22.0, 22.0), Size(2.0, 2.0)), "small");
+        hash.insert(Rect(Point(80.0, 80.0), Size(5.0, 5.0)), "far");
+
+        let found = hash.query_rect(Rect(Point(15.0, 15.0), Size(15.0, 15.0)));
+
+        assert_eq!(found, vec![(big, &"big"), (small, &"small")]);
+    }
+
+    #[test]
+    fn it_should_handle_huge_rects() {
+        let mut hash = SpatialHash::new(1.0);
+        let key = hash.insert(Rect(Point(0.0, 0.0), Size(1.0, 1.0)), "a");
+        let huge = Rect(Point(-1.0e30, -1.0e30), Size(2.0e30, 2.0e30));
+
+        assert_eq!(hash.query_rect(huge), vec![(key, &"a")]);
+    }
+
+    #[test]
+    fn it_should_not_return_items_in_same_cell_which_do_not_overlap() {
+        let mut hash = SpatialHash::new(100.0);
+        hash.insert(Rect(Point(0.0, 0.0), Size(5.0, 5.0)), "a");
+
+        assert_eq!(
+            hash.query_rect(Rect(Point(50.0, 50.0), Size(5.0, 5.0))),
+            vec![]
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_point {
+    use super::*;
+
+    #[test]
+    fn it_should_handle_negative_positions() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(-25.0, -25.0), Size(10.0, 10.0)), "a");
+
+        assert_eq!(hash.query_point(Point(-20.0, -20.0)), vec![(key, &"a")]);
+        assert_eq!(hash.query_point(Point(-5.0, -5.0)), vec![]);
+    }
+}
+
+#[cfg(test)]
+mod query_line {
+    use super::*;
+
+    #[test]
+    fn it_should_return_items_the_line_crosses() {
+        let mut hash = SpatialHash::new(10.0);
+        let hit = hash.insert(Rect(Point(40.0, 40.0), Size(10.0, 10.0)), "hit");
+        hash.insert(Rect(Point(40.0, 0.0), Size(10.0, 10.0)), "miss");
+
+        let found = hash.query_line(Line(Point(0.0, 0.0), Point(100.0, 100.0)));
+
+        assert_eq!(found, vec![(hit, &"hit")]);
+    }
+
+    #[test]
+    fn it_should_find_the_same_items_as_checking_every_item() {
+        let mut hash = SpatialHash::new(1.0);
+        for y in 0..20 {
+            for x in 0..20 {
+                let rect = Rect(Point(x as f32 + 0.3, y as f32 + 0.3), Size(0.4, 0.4));
+                hash.insert(rect, (x, y));
+            }
+        }
+
+        for line in [
+            Line(Point(0.5, 0.5), Point(10.5, 5.5)),
+            Line(Point(10.5, 5.5), Point(0.5, 0.5)),
+            Line(Point(2.5, 0.5), Point(3.5, 17.5)),
+            Line(Point(4.5, 18.0), Point(4.5, 1.0)),
+            Line(Point(1.0, 7.5), Point(15.0, 7.5)),
+            Line(Point(0.0, 0.0), Point(6.0, 6.0)),
+        ] {
+            let mut expected: Vec<(SpatialHashKey, &(i32, i32))> = hash
+                .iter()
+                .filter(|(_, rect, _)| line.intersect_rect(*rect).is_some())
+                .map(|(key, _, value)| (key, value))
+                .collect();
+            expected.sort_by_key(|(key, _)| *key);
+
+            assert!(!expected.is_empty(), "{:?}", line);
+            assert_eq!(hash.query_line(line), expected, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn it_should_handle_huge_lines() {
+        let mut hash = SpatialHash::new(1.0);
+        let key = hash.insert(Rect(Point(-0.5, -0.5), Size(1.0, 1.0)), "a");
+
+        assert_eq!(
+            hash.query_line(Line(Point(-1.0e6, -1.0e6), Point(1.0e6, 1.0e6))),
+            vec![(key, &"a")]
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_nearest {
+    use super::*;
+
+    #[test]
+    fn it_should_return_nearest_in_order() {
+        let mut hash = SpatialHash::new(10.0);
+        let far = hash.insert(Rect(Point(90.0, 90.0), Size(2.0, 2.0)), "far");
+        let near = hash.insert(Rect(Point(4.0, 4.0), Size(2.0, 2.0)), "near");
+        let middle = hash.insert(Rect(Point(30.0, 0.0), Size(2.0, 2.0)), "middle");
+
+        assert_eq!(
+            hash.query_nearest(Point(0.0, 0.0), 2),
+            vec![(near, &"near"), (middle, &"middle")]
+        );
+        assert_eq!(
+            hash.query_nearest(Point(0.0, 0.0), 10),
+            vec![(near, &"near"), (middle, &"middle"), (far, &"far")]
+        );
+    }
+
+    #[test]
+    fn it_should_find_nearer_item_in_a_further_cell() {
+        let mut hash = SpatialHash::new(10.0);
+        // Same cell as the point, but its centre is far away.
+        let big = hash.insert(Rect(Point(0.0, 0.0), Size(100.0, 100.0)), "big");
+        let small = hash.insert(Rect(Point(11.0, 0.0), Size(2.0, 2.0)), "small");
+
+        assert_eq!(
+            hash.query_nearest(Point(9.0, 1.0), 1),
+            vec![(small, &"small")]
+        );
+        assert_eq!(
+            hash.query_nearest(Point(50.0, 50.0), 1),
+            vec![(big, &"big")]
+        );
+    }
+
+    #[test]
+    fn it_should_handle_items_at_opposite_extremes() {
+        let mut hash = SpatialHash::new(10.0);
+        let key = hash.insert(Rect(Point(-1.0e30, 0.0), Size(1.0, 1.0)), "far");
+
+        assert_eq!(
+            hash.query_nearest(Point(1.0e30, 0.0), 1),
+            vec![(key, &"far")]
+        );
+    }
+
+    #[test]
+    fn it_should_not_walk_empty_cells_to_a_distant_outlier() {
+        let mut hash = SpatialHash::new(1.0);
+        let near = hash.insert(Rect(Point(0.0, 0.0), Size(1.0, 1.0)), "near");
+        let far = hash.insert(Rect(Point(1.0e9, 1.0e9), Size(1.0, 1.0)), "far");
+
+        assert_eq!(
+            hash.query_nearest(Point(0.0, 0.0), 2),
+            vec![(near, &"near"), (far, &"far")]
+        );
+    }
+
+    #[test]
+    fn it_should_return_nothing_for_zero() {
+        let mut hash = SpatialHash::new(10.0);
+        hash.insert(Rect(Point(0.0, 0.0), Size(2.0, 2.0)), "a");
+
+        assert_eq!(hash.query_nearest(Point(0.0, 0.0), 0), vec![]);
+    }
+}
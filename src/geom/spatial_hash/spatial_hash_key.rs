@@ -0,0 +1,12 @@
+/// Identifies an item stored in a `SpatialHash`.
+///
+/// Keys are never reused, so a key for a removed item will not
+/// accidentally refer to a newer item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpatialHashKey(usize);
+
+impl SpatialHashKey {
+    pub(crate) fn new(id: usize) -> Self {
+        Self(id)
+    }
+}
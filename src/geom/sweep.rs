@@ -0,0 +1,337 @@
+//! Continuous collisions, for shapes which are moving.
+//!
+//! A static test like `overlaps` can miss fast moving shapes, as they can
+//! jump straight past thin objects in a single step. These instead check the
+//! whole path the shape travels along, and return the first point it hits.
+//!
+//! The velocity is how far the shape moves across the whole step.
+//! Shapes which are already overlapping hit at the time 0.
+
+use crate::num::Num;
+
+use crate::geom::Circle;
+use crate::geom::Contact;
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use crate::geom::vector::dot;
+use crate::geom::vector::normalise;
+use crate::geom::vector::perpendicular;
+
+mod sweep_hit;
+pub use self::sweep_hit::*;
+
+impl<N: Num> Circle<N> {
+    pub fn sweep_against_line(&self, velocity: Size<N>, line: Line<N>) -> Option<SweepHit> {
+        if let Some(contact) = line.collide_circle(*self) {
+            return Some(overlapping_hit(contact, self.to_f32().centre()));
+        }
+
+        sweep_circle_against_line(self.to_f32(), velocity.to_f32().to_point(), line.to_f32())
+    }
+
+    pub fn sweep_against_rect(&self, velocity: Size<N>, rect: Rect<N>) -> Option<SweepHit> {
+        if let Some(contact) = rect.collide_circle(*self) {
+            return Some(overlapping_hit(contact, self.to_f32().centre()));
+        }
+
+        let self_f32 = self.to_f32();
+        let velocity_f32 = velocity.to_f32().to_point();
+        let rect_f32 = rect.to_f32();
+
+        [
+            rect_f32.bottom_edge(),
+            rect_f32.right_edge(),
+            rect_f32.top_edge(),
+            rect_f32.left_edge(),
+        ]
+        .into_iter()
+        .filter_map(|edge| sweep_circle_against_line(self_f32, velocity_f32, edge))
+        .min_by(|a, b| a.time().total_cmp(&b.time()))
+    }
+}
+
+impl<N: Num> Rect<N> {
+    /// The contact point is the middle of where the two `Rect`s touch.
+    pub fn sweep_against_rect(&self, velocity: Size<N>, other: Rect<N>) -> Option<SweepHit> {
+        if let Some(contact) = other.collide_rect(*self) {
+            return Some(overlapping_hit(contact, self.to_f32().centre()));
+        }
+
+        let self_f32 = self.to_f32();
+        let other_f32 = other.to_f32();
+        let velocity_f32 = velocity.to_f32();
+
+        let (entry_x, exit_x) = axis_entry_exit(
+            (self_f32.left_x(), self_f32.right_x()),
+            (other_f32.left_x(), other_f32.right_x()),
+            velocity_f32.width(),
+        )?;
+        let (entry_y, exit_y) = axis_entry_exit(
+            (self_f32.bottom_y(), self_f32.top_y()),
+            (other_f32.bottom_y(), other_f32.top_y()),
+            velocity_f32.height(),
+        )?;
+
+        let entry = entry_x.max(entry_y);
+        let exit = exit_x.min(exit_y);
+        if exit <= entry || !(0.0..=1.0).contains(&entry) {
+            return None;
+        }
+
+        let moved = self_f32 + (velocity_f32 * entry).to_point();
+        let (normal, point) = if entry_x > entry_y {
+            let x = if velocity_f32.width() > 0.0 {
+                other_f32.left_x()
+            } else {
+                other_f32.right_x()
+            };
+            let bottom = moved.bottom_y().max(other_f32.bottom_y());
+            let top = moved.top_y().min(other_f32.top_y());
+            let normal = Size(-velocity_f32.width().signum(), 0.0);
+
+            (normal, Point(x, (bottom + top) / 2.0))
+        } else {
+            let y = if velocity_f32.height() > 0.0 {
+                other_f32.bottom_y()
+            } else {
+                other_f32.top_y()
+            };
+            let left = moved.left_x().max(other_f32.left_x());
+            let right = moved.right_x().min(other_f32.right_x());
+            let normal = Size(0.0, -velocity_f32.height().signum());
+
+            (normal, Point((left + right) / 2.0, y))
+        };
+
+        Some(SweepHit::new(entry, point, normal))
+    }
+}
+
+/// For shapes which are already overlapping at the start.
+fn overlapping_hit(contact: Contact, fallback_point: Point<f32>) -> SweepHit {
+    let point = contact.points().first().copied().unwrap_or(fallback_point);
+
+    SweepHit::new(0.0, point, contact.normal())
+}
+
+/// Returns the times when the moving span enters, and then leaves, the other.
+fn axis_entry_exit(
+    (moving_min, moving_max): (f32, f32),
+    (other_min, other_max): (f32, f32),
+    velocity: f32,
+) -> Option<(f32, f32)> {
+    if velocity == 0.0 {
+        if moving_max <= other_min || other_max <= moving_min {
+            return None;
+        }
+
+        return Some((f32::NEG_INFINITY, f32::INFINITY));
+    }
+
+    let entry = if velocity > 0.0 {
+        (other_min - moving_max) / velocity
+    } else {
+        (other_max - moving_min) / velocity
+    };
+    let exit = if velocity > 0.0 {
+        (other_max - moving_min) / velocity
+    } else {
+        (other_min - moving_max) / velocity
+    };
+
+    Some((entry, exit))
+}
+
+/// Expects the circle to not be overlapping the line at the start.
+fn sweep_circle_against_line(
+    circle: Circle<f32>,
+    velocity: Point<f32>,
+    line: Line<f32>,
+) -> Option<SweepHit> {
+    let face_hit = sweep_circle_against_line_face(circle, velocity, line);
+    let start_hit = sweep_circle_against_point(circle, velocity, line.start());
+    let end_hit = sweep_circle_against_point(circle, velocity, line.end());
+
+    [face_hit, start_hit, end_hit]
+        .into_iter()
+        .flatten()
+        .min_by(|a, b| a.time().total_cmp(&b.time()))
+}
+
+/// Finds when the edge of the circle, closest to the line, crosses it.
+fn sweep_circle_against_line_face(
+    circle: Circle<f32>,
+    velocity: Point<f32>,
+    line: Line<f32>,
+) -> Option<SweepHit> {
+    let mut normal = normalise(perpendicular(line.end() - line.start()));
+    if dot(circle.centre() - line.start(), normal) < 0.0 {
+        normal = -normal;
+    }
+
+    if dot(velocity, normal) >= 0.0 {
+        return None;
+    }
+
+    let leading_point = circle.centre() - normal * circle.radius().abs();
+    let travelled = Line(leading_point, leading_point + velocity);
+    let point = travelled.intersect_line(line)?;
+    let time = dot(point - leading_point, velocity) / dot(velocity, velocity);
+
+    Some(SweepHit::new(time, point, normal.to_size()))
+}
+
+/// Finds when the circle first touches the point.
+fn sweep_circle_against_point(
+    circle: Circle<f32>,
+    velocity: Point<f32>,
+    point: Point<f32>,
+) -> Option<SweepHit> {
+    let radius = circle.radius().abs();
+    let offset = circle.centre() - point;
+
+    let a = dot(velocity, velocity);
+    let b = 2.0 * dot(offset, velocity);
+    let c = dot(offset, offset) - radius * radius;
+    if a == 0.0 {
+        return None;
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let time = (-b - discriminant.sqrt()) / (2.0 * a);
+    if !(0.0..=1.0).contains(&time) {
+        return None;
+    }
+
+    let centre = circle.centre() + velocity * time;
+    let normal = normalise(centre - point);
+
+    Some(SweepHit::new(time, point, normal.to_size()))
+}
+
+#[cfg(test)]
+mod sweep_against_line {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_a_thin_wall_it_would_tunnel_through() {
+        let circle = Circle(Point(0.0, 5.0), 1.0);
+        let wall = Line(Point(10.0, 0.0), Point(10.0, 10.0));
+        let hit = circle.sweep_against_line(Size(100.0, 0.0), wall).unwrap();
+
+        assert_approx_eq!(hit.time(), 0.09);
+        assert_approx_point_eq(hit.point(), Point(10.0, 5.0));
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_hit_the_end_of_a_line() {
+        let circle = Circle(Point(0.0, 11.0), 2.0);
+        let wall = Line(Point(10.0, 0.0), Point(10.0, 10.0));
+        let hit = circle.sweep_against_line(Size(20.0, 0.0), wall).unwrap();
+
+        assert_approx_point_eq(hit.point(), Point(10.0, 10.0));
+        assert_approx_eq!(hit.time(), (10.0 - 3.0_f32.sqrt()) / 20.0);
+    }
+
+    #[test]
+    fn it_should_miss_when_not_moving_far_enough() {
+        let circle = Circle(Point(0.0, 5.0), 1.0);
+        let wall = Line(Point(10.0, 0.0), Point(10.0, 10.0));
+
+        assert_eq!(circle.sweep_against_line(Size(5.0, 0.0), wall), None);
+    }
+
+    #[test]
+    fn it_should_miss_when_moving_away() {
+        let circle = Circle(Point(0.0, 5.0), 1.0);
+        let wall = Line(Point(10.0, 0.0), Point(10.0, 10.0));
+
+        assert_eq!(circle.sweep_against_line(Size(-50.0, 0.0), wall), None);
+    }
+
+    #[test]
+    fn it_should_hit_at_zero_when_already_overlapping() {
+        let circle = Circle(Point(9.5, 5.0), 1.0);
+        let wall = Line(Point(10.0, 0.0), Point(10.0, 10.0));
+        let hit = circle.sweep_against_line(Size(5.0, 0.0), wall).unwrap();
+
+        assert_eq!(hit.time(), 0.0);
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+}
+
+#[cfg(test)]
+mod sweep_against_rect {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use crate::geom::testing_utils::assert_approx_size_eq;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn it_should_hit_rect_moving_right() {
+        let moving = Rect(Point(0.0, 0.0), Size(2.0, 2.0));
+        let wall = Rect(Point(10.0, -5.0), Size(1.0, 10.0));
+        let hit = moving.sweep_against_rect(Size(16.0, 0.0), wall).unwrap();
+
+        assert_approx_eq!(hit.time(), 0.5);
+        assert_approx_point_eq(hit.point(), Point(10.0, 1.0));
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_hit_rect_moving_down() {
+        let moving = Rect(Point(0.0, 10.0), Size(2.0, 2.0));
+        let floor = Rect(Point(-5.0, 0.0), Size(10.0, 2.0));
+        let hit = moving.sweep_against_rect(Size(0.0, -16.0), floor).unwrap();
+
+        assert_approx_eq!(hit.time(), 0.5);
+        assert_approx_size_eq(hit.normal(), Size(0.0, 1.0));
+    }
+
+    #[test]
+    fn it_should_miss_rect_when_passing_by() {
+        let moving = Rect(Point(0.0, 20.0), Size(2.0, 2.0));
+        let wall = Rect(Point(10.0, -5.0), Size(1.0, 10.0));
+
+        assert_eq!(moving.sweep_against_rect(Size(16.0, 0.0), wall), None);
+    }
+
+    #[test]
+    fn it_should_hit_rect_with_circle() {
+        let circle = Circle(Point(0.0, 0.0), 1.0);
+        let wall = Rect(Point(10.0, -5.0), Size(1.0, 10.0));
+        let hit = circle.sweep_against_rect(Size(18.0, 0.0), wall).unwrap();
+
+        assert_approx_eq!(hit.time(), 0.5);
+        assert_approx_point_eq(hit.point(), Point(10.0, 0.0));
+        assert_approx_size_eq(hit.normal(), Size(-1.0, 0.0));
+    }
+
+    #[test]
+    fn it_should_hit_rect_corner_with_circle() {
+        let circle = Circle(Point(0.0, 6.0), 2.0);
+        let wall = Rect(Point(10.0, -5.0), Size(1.0, 10.0));
+        let hit = circle.sweep_against_rect(Size(20.0, 0.0), wall).unwrap();
+
+        assert_approx_point_eq(hit.point(), Point(10.0, 5.0));
+    }
+
+    #[test]
+    fn it_should_miss_rect_with_circle_passing_by() {
+        let circle = Circle(Point(0.0, 8.0), 2.0);
+        let wall = Rect(Point(10.0, -5.0), Size(1.0, 10.0));
+
+        assert_eq!(circle.sweep_against_rect(Size(20.0, 0.0), wall), None);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::geom::Point;
+use crate::geom::Size;
+
+/// Where a moving shape first hits another.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SweepHit {
+    time: f32,
+    point: Point<f32>,
+    normal: Size<f32>,
+}
+
+impl SweepHit {
+    pub fn new(time: f32, point: Point<f32>, normal: Size<f32>) -> Self {
+        Self {
+            time,
+            point,
+            normal,
+        }
+    }
+
+    /// How far along the velocity the hit happens, from 0.0 to 1.0.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Where the two shapes touch.
+    pub fn point(&self) -> Point<f32> {
+        self.point
+    }
+
+    /// The direction of the surface that was hit,
+    /// pointing back towards the moving shape.
+    pub fn normal(&self) -> Size<f32> {
+        self.normal
+    }
+}
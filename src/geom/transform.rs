@@ -3,8 +3,10 @@ use std::ops::Add;
 
 use num_traits::sign::Signed;
 
+use crate::geom::Affine2;
 use crate::geom::Line;
 use crate::geom::Point;
+use crate::geom::Polygon;
 use crate::geom::Size;
 use crate::num::Num;
 use crate::num::NumIdentity;
@@ -67,6 +69,31 @@ impl<N: Num> Transform<N> {
     pub fn scale(self) -> Size<N> {
         self.scale
     }
+
+    pub fn to_affine2(self) -> Affine2 {
+        Affine2::from(self)
+    }
+
+    /// Returns a transform which applies this, and then the other.
+    ///
+    /// The two are composed as matrices, and then turned back into a `Transform`,
+    /// which cannot hold skew. This is exact when this transform uses the same
+    /// scale for width and height, or when the other has no rotation
+    /// (or only turns by multiples of a quarter turn).
+    /// Otherwise the skew is lost.
+    /// For nesting transforms exactly, use `to_affine2` and compose those.
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        self.to_affine2().then(other.to_affine2()).into()
+    }
+
+    /// Returns the transform which undoes this one.
+    ///
+    /// This is exact when the width and height are scaled by the same amount.
+    /// `None` is returned if the scale is zero.
+    pub fn inverse(self) -> Option<Self> {
+        self.to_affine2().inverse().map(Self::from)
+    }
 }
 
 impl<N> Transform<N>
@@ -120,6 +147,23 @@ where
     }
 }
 
+impl<N> Add<Polygon<N>> for Transform<N>
+where
+    N: Num,
+{
+    type Output = Polygon<N>;
+
+    fn add(self, polygon: Self::Output) -> Self::Output {
+        Polygon::new_from_points(
+            polygon
+                .into_points()
+                .into_iter()
+                .map(|point| self + point)
+                .collect(),
+        )
+    }
+}
+
 impl<N> From<Point<N>> for Transform<N>
 where
     N: Num,
@@ -137,3 +181,61 @@ where
         Self::new().set_scale(s)
     }
 }
+
+#[cfg(test)]
+mod then {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_apply_both_transforms_in_order() {
+        let first = Transform::new()
+            .set_position(Point(10.0, 0.0))
+            .set_rotation(TAU * 0.25);
+        let second = Transform::new()
+            .set_position(Point(0.0, 5.0))
+            .set_scale(Size(2.0, 2.0));
+        let point = Point(1.0, 2.0);
+
+        assert_approx_point_eq(first.then(second) + point, second + (first + point));
+    }
+
+    #[test]
+    fn it_should_be_exact_when_other_has_no_rotation() {
+        let first = Transform::new()
+            .set_scale(Size(3.0, 1.0))
+            .set_rotation(TAU * 0.1);
+        let second = Transform::new()
+            .set_position(Point(0.0, 5.0))
+            .set_scale(Size(2.0, 0.5));
+        let point = Point(1.0, 2.0);
+
+        assert_approx_point_eq(first.then(second) + point, second + (first + point));
+    }
+}
+
+#[cfg(test)]
+mod inverse {
+    use super::*;
+    use crate::geom::testing_utils::assert_approx_point_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn it_should_undo_the_transform() {
+        let transform = Transform::new()
+            .set_position(Point(10.0, 20.0))
+            .set_scale(Size(3.0, 3.0))
+            .set_rotation(TAU * 0.1);
+        let point = Point(5.0, -4.0);
+
+        assert_approx_point_eq(transform.inverse().unwrap() + (transform + point), point);
+    }
+
+    #[test]
+    fn it_should_return_none_for_zero_scale() {
+        let transform: Transform<f32> = Transform::new().set_scale(Size(0.0, 0.0));
+
+        assert_eq!(transform.inverse(), None);
+    }
+}
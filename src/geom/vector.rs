@@ -0,0 +1,47 @@
+//! Maths for treating a `Point<f32>` as a 2D vector.
+//!
+//! These are shared between the shapes, for use within the crate.
+
+use crate::geom::Line;
+use crate::geom::Point;
+
+pub(crate) fn dot(a: Point<f32>, b: Point<f32>) -> f32 {
+    a.x() * b.x() + a.y() * b.y()
+}
+
+/// The z part of a 3D cross product.
+/// This is positive when `b` is counter clockwise to `a`.
+pub(crate) fn cross(a: Point<f32>, b: Point<f32>) -> f32 {
+    a.x() * b.y() - a.y() * b.x()
+}
+
+pub(crate) fn length(point: Point<f32>) -> f32 {
+    point.x().hypot(point.y())
+}
+
+/// Returns the point scaled to a length of 1.
+/// A zero point is returned unchanged.
+pub(crate) fn normalise(point: Point<f32>) -> Point<f32> {
+    let length = length(point);
+    if length == 0.0 {
+        return point;
+    }
+
+    point / length
+}
+
+/// Returns the point rotated a quarter turn, counter clockwise.
+pub(crate) fn perpendicular(point: Point<f32>) -> Point<f32> {
+    Point(-point.y(), point.x())
+}
+
+pub(crate) fn closest_point_on_line(line: Line<f32>, point: Point<f32>) -> Point<f32> {
+    let diff = line.end() - line.start();
+    let length_sqrd = dot(diff, diff);
+    if length_sqrd == 0.0 {
+        return line.start();
+    }
+
+    let n = (dot(point - line.start(), diff) / length_sqrd).clamp(0.0, 1.0);
+    line.start() + diff * n
+}
@@ -0,0 +1,202 @@
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+use crate::Colour;
+use crate::Vec2D;
+
+mod image_parse_error;
+pub use self::image_parse_error::*;
+
+mod pnm;
+
+mod pnm_format;
+pub use self::pnm_format::*;
+
+/// A grid of colours, which can be drawn onto and saved.
+///
+/// Like `Vec2D`, the y axis goes up,
+/// so `Point(0, 0)` is the bottom left pixel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pixels: Vec2D<Colour>,
+}
+
+impl Image {
+    /// Creates a new Image of the size given, filled with the colour.
+    pub fn new(size: Size<usize>, colour: Colour) -> Self {
+        Self::new_from_vec2d(Vec2D::new(size, colour))
+    }
+
+    pub fn new_from_vec2d(pixels: Vec2D<Colour>) -> Self {
+        Self { pixels }
+    }
+
+    pub fn pixels(&self) -> &Vec2D<Colour> {
+        &self.pixels
+    }
+
+    /// Allows direct access to the pixels,
+    /// such as for using the drawing functions on `Vec2D`.
+    pub fn pixels_mut(&mut self) -> &mut Vec2D<Colour> {
+        &mut self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec2D<Colour> {
+        self.pixels
+    }
+
+    pub fn size(&self) -> Size<usize> {
+        self.pixels.size()
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    /// Returns the colour at the position given,
+    /// or None if it is outside of this Image.
+    pub fn get(&self, pos: Point<usize>) -> Option<Colour> {
+        if self.is_inside(pos) {
+            Some(self.pixels[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the colour at the position given.
+    /// Positions outside of this Image are ignored.
+    pub fn set(&mut self, pos: Point<usize>, colour: Colour) {
+        if self.is_inside(pos) {
+            self.pixels[pos] = colour;
+        }
+    }
+
+    /// Draws the colour on top of the pixel,
+    /// using Porter-Duff source-over compositing.
+    ///
+    /// Positions outside of this Image are ignored.
+    pub fn blend_pixel(&mut self, pos: Point<usize>, colour: Colour) {
+        if self.is_inside(pos) {
+            self.pixels[pos] = colour.source_over(self.pixels[pos]);
+        }
+    }
+
+    /// Blends the colour over every pixel within the area.
+    ///
+    /// The area is clipped to the size of this Image.
+    pub fn fill_rect(&mut self, area: Rect<usize>, colour: Colour) {
+        let Some(area) = area.intersect_rect(self.pixels.rect()) else {
+            return;
+        };
+
+        for (pixel, _) in self.pixels.iter_of_mut(area) {
+            *pixel = colour.source_over(*pixel);
+        }
+    }
+
+    fn is_inside(&self, Point(x, y): Point<usize>) -> bool {
+        x < self.width() && y < self.height()
+    }
+}
+
+impl From<Vec2D<Colour>> for Image {
+    fn from(pixels: Vec2D<Colour>) -> Self {
+        Self::new_from_vec2d(pixels)
+    }
+}
+
+#[cfg(test)]
+mod blend_pixel {
+    use super::*;
+
+    #[test]
+    fn it_should_replace_with_opaque_colour() {
+        let mut image = Image::new(Size(2, 2), Colour::BLUE);
+        image.blend_pixel(Point(1, 0), Colour::RED);
+
+        assert_eq!(image.get(Point(1, 0)), Some(Colour::RED));
+        assert_eq!(image.get(Point(0, 0)), Some(Colour::BLUE));
+    }
+
+    #[test]
+    fn it_should_mix_with_translucent_colour() {
+        let mut image = Image::new(Size(1, 1), Colour::BLACK);
+        image.blend_pixel(Point(0, 0), Colour::WHITE.replace_alpha_u32(51));
+
+        assert_eq!(
+            image.get(Point(0, 0)),
+            Some(Colour::new_from_u8s(51, 51, 51, 255))
+        );
+    }
+
+    #[test]
+    fn it_should_combine_alpha() {
+        let mut image = Image::new(Size(1, 1), Colour::RED.replace_alpha_u32(128));
+        image.blend_pixel(Point(0, 0), Colour::BLUE.replace_alpha_u32(128));
+
+        let colour = image.get(Point(0, 0)).unwrap();
+        assert_eq!(colour.alpha_u8(), 192);
+        assert_eq!(colour.red_u8(), 85);
+        assert_eq!(colour.blue_u8(), 170);
+    }
+
+    #[test]
+    fn it_should_keep_background_under_transparent_colour() {
+        let background = Colour::new_from_u8s(10, 20, 30, 40);
+        let mut image = Image::new(Size(1, 1), background);
+        image.blend_pixel(Point(0, 0), Colour::WHITE.replace_alpha_u32(0));
+
+        assert_eq!(image.get(Point(0, 0)), Some(background));
+    }
+
+    #[test]
+    fn it_should_ignore_pixels_outside() {
+        let mut image = Image::new(Size(1, 1), Colour::BLACK);
+        image.blend_pixel(Point(1, 0), Colour::WHITE);
+
+        assert_eq!(image, Image::new(Size(1, 1), Colour::BLACK));
+    }
+}
+
+#[cfg(test)]
+mod fill_rect {
+    use super::*;
+
+    #[test]
+    fn it_should_fill_area() {
+        let mut image = Image::new(Size(3, 3), Colour::BLACK);
+        image.fill_rect(Rect(Point(1, 1), Size(2, 1)), Colour::GREEN);
+
+        for (colour, pos) in image.pixels() {
+            let expected = if pos.y() == 1 && pos.x() >= 1 {
+                Colour::GREEN
+            } else {
+                Colour::BLACK
+            };
+
+            assert_eq!(colour, expected, "{:?}", pos);
+        }
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut image = Image::new(Size(2, 2), Colour::BLACK);
+        image.fill_rect(Rect(Point(1, 0), Size(5, 5)), Colour::WHITE);
+
+        assert_eq!(image.get(Point(0, 0)), Some(Colour::BLACK));
+        assert_eq!(image.get(Point(1, 0)), Some(Colour::WHITE));
+        assert_eq!(image.get(Point(1, 1)), Some(Colour::WHITE));
+    }
+
+    #[test]
+    fn it_should_do_nothing_when_outside() {
+        let mut image = Image::new(Size(2, 2), Colour::BLACK);
+        image.fill_rect(Rect(Point(4, 4), Size(2, 2)), Colour::WHITE);
+
+        assert_eq!(image, Image::new(Size(2, 2), Colour::BLACK));
+    }
+}
@@ -0,0 +1,23 @@
+use std::convert::From;
+use std::num::ParseIntError;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ImageParseError {
+    /// The data does not start with a supported magic number.
+    UnknownFormat,
+
+    /// The header is missing values, or has values which are not supported.
+    InvalidHeader,
+
+    /// A pixel value is not a number, or is larger than the maximum value.
+    InvalidValue,
+
+    /// The data ended before every pixel was read.
+    UnexpectedEnd,
+}
+
+impl From<ParseIntError> for ImageParseError {
+    fn from(_: ParseIntError) -> Self {
+        Self::InvalidValue
+    }
+}
@@ -0,0 +1,462 @@
+use std::str;
+
+use crate::geom::Point;
+use crate::geom::Size;
+use crate::Colour;
+use crate::Vec2D;
+
+use super::Image;
+use super::ImageParseError;
+use super::PnmFormat;
+
+impl Image {
+    /// Encodes this Image in one of the Netpbm formats.
+    ///
+    /// Rows are written top first, as image viewers expect.
+    pub fn to_pnm_bytes(&self, format: PnmFormat) -> Vec<u8> {
+        let (width, height) = (self.width(), self.height());
+        let mut bytes = match format {
+            PnmFormat::PpmAscii => format!("P3\n{} {}\n255\n", width, height),
+            PnmFormat::PpmBinary => format!("P6\n{} {}\n255\n", width, height),
+            PnmFormat::Pam => format!(
+                "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+                width, height
+            ),
+        }
+        .into_bytes();
+
+        for y in (0..height).rev() {
+            for x in 0..width {
+                let colour = self.pixels[Point(x, y)];
+                let rgb = [colour.red_u8(), colour.green_u8(), colour.blue_u8()];
+
+                match format {
+                    PnmFormat::PpmAscii => {
+                        bytes.extend(format!("{} {} {}\n", rgb[0], rgb[1], rgb[2]).into_bytes());
+                    }
+                    PnmFormat::PpmBinary => bytes.extend(rgb),
+                    PnmFormat::Pam => {
+                        bytes.extend(rgb);
+                        bytes.push(colour.alpha_u8());
+                    }
+                }
+            }
+        }
+
+        bytes
+    }
+
+    /// Decodes a PPM (both `P3` and `P6`) or a PAM (`P7`).
+    ///
+    /// Values are scaled from the maximum value in the file, to between 0 and 255.
+    /// PAM files may be greyscale or RGB, with or without alpha.
+    /// Anything without alpha is fully opaque.
+    pub fn from_pnm_bytes(bytes: &[u8]) -> Result<Self, ImageParseError> {
+        let mut reader = PnmReader::new(bytes);
+        let magic = reader
+            .next_token()
+            .map_err(|_| ImageParseError::UnknownFormat)?;
+
+        match magic {
+            "P3" => reader.read_ppm(false),
+            "P6" => reader.read_ppm(true),
+            "P7" => reader.read_pam(),
+            _ => Err(ImageParseError::UnknownFormat),
+        }
+    }
+}
+
+/// The layout of the pixel data, after the header.
+#[derive(Copy, Clone, Debug)]
+struct PnmHeader {
+    size: Size<usize>,
+    depth: usize,
+    max_value: u32,
+    is_binary: bool,
+}
+
+struct PnmReader<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> PnmReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, index: 0 }
+    }
+
+    fn read_ppm(mut self, is_binary: bool) -> Result<Image, ImageParseError> {
+        let width = self.next_header_number()?;
+        let height = self.next_header_number()?;
+        let max_value = to_max_value(self.next_header_number()?)?;
+
+        // A single whitespace separates the header from binary data.
+        if is_binary {
+            self.next_byte()?;
+        }
+
+        self.read_pixels(PnmHeader {
+            size: Size(width, height),
+            depth: 3,
+            max_value,
+            is_binary,
+        })
+    }
+
+    fn read_pam(mut self) -> Result<Image, ImageParseError> {
+        let mut width = None;
+        let mut height = None;
+        let mut depth = None;
+        let mut max_value = None;
+
+        loop {
+            let line = self.next_line()?;
+            let mut words = line.split_whitespace();
+            let Some(key) = words.next() else {
+                continue;
+            };
+
+            let mut parse_value = || -> Result<usize, ImageParseError> {
+                words
+                    .next()
+                    .and_then(|word| word.parse().ok())
+                    .ok_or(ImageParseError::InvalidHeader)
+            };
+
+            match key {
+                "ENDHDR" => break,
+                "WIDTH" => width = Some(parse_value()?),
+                "HEIGHT" => height = Some(parse_value()?),
+                "DEPTH" => depth = Some(parse_value()?),
+                "MAXVAL" => max_value = Some(to_max_value(parse_value()?)?),
+                _ => {}
+            }
+        }
+
+        match (width, height, depth, max_value) {
+            (Some(width), Some(height), Some(depth), Some(max_value)) => {
+                self.read_pixels(PnmHeader {
+                    size: Size(width, height),
+                    depth,
+                    max_value,
+                    is_binary: true,
+                })
+            }
+            _ => Err(ImageParseError::InvalidHeader),
+        }
+    }
+
+    fn read_pixels(&mut self, header: PnmHeader) -> Result<Image, ImageParseError> {
+        let is_max_value_valid = 0 < header.max_value && header.max_value <= u16::MAX as u32;
+        if !is_max_value_valid || !(1..=4).contains(&header.depth) {
+            return Err(ImageParseError::InvalidHeader);
+        }
+
+        let num_samples = header
+            .size
+            .width()
+            .checked_mul(header.size.height())
+            .and_then(|num_pixels| num_pixels.checked_mul(header.depth))
+            .ok_or(ImageParseError::InvalidHeader)?;
+
+        // Every sample takes at least one byte, and two when binary above 255.
+        // Checked before allocating, so a huge size in the header can't exhaust memory.
+        let bytes_per_sample = if header.is_binary && header.max_value > u8::MAX as u32 {
+            2
+        } else {
+            1
+        };
+        let remaining_bytes = self.bytes.len() - self.index;
+        let is_too_short = num_samples
+            .checked_mul(bytes_per_sample)
+            .is_none_or(|num_bytes| num_bytes > remaining_bytes);
+        if is_too_short {
+            return Err(ImageParseError::UnexpectedEnd);
+        }
+
+        let mut pixels = Vec2D::new(header.size, Colour::BLACK);
+        for y in (0..header.size.height()).rev() {
+            for x in 0..header.size.width() {
+                pixels[Point(x, y)] = self.next_pixel(header)?;
+            }
+        }
+
+        Ok(Image::new_from_vec2d(pixels))
+    }
+
+    fn next_pixel(&mut self, header: PnmHeader) -> Result<Colour, ImageParseError> {
+        let mut samples = [0; 4];
+        for sample in samples.iter_mut().take(header.depth) {
+            *sample = self.next_sample(header)?;
+        }
+
+        match header.depth {
+            1 => Ok(Colour::new_from_u8s(
+                samples[0], samples[0], samples[0], 255,
+            )),
+            2 => Ok(Colour::new_from_u8s(
+                samples[0], samples[0], samples[0], samples[1],
+            )),
+            3 => Ok(Colour::new_from_u8s(
+                samples[0], samples[1], samples[2], 255,
+            )),
+            4 => Ok(Colour::new_from_u8s(
+                samples[0], samples[1], samples[2], samples[3],
+            )),
+            _ => Err(ImageParseError::InvalidHeader),
+        }
+    }
+
+    /// Reads one channel of a pixel, scaled to be between 0 and 255.
+    fn next_sample(&mut self, header: PnmHeader) -> Result<u8, ImageParseError> {
+        let value = if !header.is_binary {
+            self.next_token()?.parse::<u32>()?
+        } else if header.max_value <= u8::MAX as u32 {
+            self.next_byte()? as u32
+        } else {
+            ((self.next_byte()? as u32) << 8) | (self.next_byte()? as u32)
+        };
+
+        if value > header.max_value {
+            return Err(ImageParseError::InvalidValue);
+        }
+
+        Ok(((value * 255 + header.max_value / 2) / header.max_value) as u8)
+    }
+
+    fn next_header_number(&mut self) -> Result<usize, ImageParseError> {
+        self.next_token()?
+            .parse()
+            .map_err(|_| ImageParseError::InvalidHeader)
+    }
+
+    /// Returns the next run of non-whitespace, skipping any comments.
+    fn next_token(&mut self) -> Result<&'a str, ImageParseError> {
+        loop {
+            match self.bytes.get(self.index) {
+                None => return Err(ImageParseError::UnexpectedEnd),
+                Some(b'#') => self.skip_past_newline(),
+                Some(byte) if byte.is_ascii_whitespace() => self.index += 1,
+                Some(_) => break,
+            }
+        }
+
+        let start = self.index;
+        while self
+            .bytes
+            .get(self.index)
+            .is_some_and(|byte| !byte.is_ascii_whitespace())
+        {
+            self.index += 1;
+        }
+
+        str::from_utf8(&self.bytes[start..self.index]).map_err(|_| ImageParseError::InvalidValue)
+    }
+
+    /// Returns the rest of the current line, without any comment.
+    fn next_line(&mut self) -> Result<&'a str, ImageParseError> {
+        if self.index >= self.bytes.len() {
+            return Err(ImageParseError::UnexpectedEnd);
+        }
+
+        let start = self.index;
+        self.skip_past_newline();
+
+        let line = &self.bytes[start..self.index];
+        let line = match line.iter().position(|byte| *byte == b'#') {
+            Some(comment_start) => &line[..comment_start],
+            None => line,
+        };
+
+        str::from_utf8(line).map_err(|_| ImageParseError::InvalidHeader)
+    }
+
+    fn next_byte(&mut self) -> Result<u8, ImageParseError> {
+        let byte = *self
+            .bytes
+            .get(self.index)
+            .ok_or(ImageParseError::UnexpectedEnd)?;
+        self.index += 1;
+
+        Ok(byte)
+    }
+
+    fn skip_past_newline(&mut self) {
+        while let Some(byte) = self.bytes.get(self.index) {
+            self.index += 1;
+
+            if *byte == b'\n' {
+                return;
+            }
+        }
+    }
+}
+
+/// Errors if the max value is too large, instead of truncating it.
+fn to_max_value(value: usize) -> Result<u32, ImageParseError> {
+    u32::try_from(value).map_err(|_| ImageParseError::InvalidHeader)
+}
+
+#[cfg(test)]
+fn new_test_image() -> Image {
+    // Rows are listed bottom first.
+    Image::new_from_vec2d(Vec2D::new_from_vecs(vec![
+        vec![Colour::RED, Colour::GREEN],
+        vec![Colour::BLUE, Colour::new_from_u8s(1, 2, 3, 4)],
+    ]))
+}
+
+#[cfg(test)]
+mod to_pnm_bytes {
+    use super::*;
+
+    #[test]
+    fn it_should_write_ascii_ppm_top_row_first() {
+        let bytes = new_test_image().to_pnm_bytes(PnmFormat::PpmAscii);
+
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "P3\n2 2\n255\n0 0 255\n1 2 3\n255 0 0\n0 255 0\n"
+        );
+    }
+
+    #[test]
+    fn it_should_write_binary_ppm() {
+        let bytes = new_test_image().to_pnm_bytes(PnmFormat::PpmBinary);
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([0, 0, 255, 1, 2, 3, 255, 0, 0, 0, 255, 0]);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn it_should_write_pam_with_alpha() {
+        let bytes = new_test_image().to_pnm_bytes(PnmFormat::Pam);
+
+        let mut expected =
+            b"P7\nWIDTH 2\nHEIGHT 2\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n".to_vec();
+        expected.extend([0, 0, 255, 255, 1, 2, 3, 4, 255, 0, 0, 255, 0, 255, 0, 255]);
+        assert_eq!(bytes, expected);
+    }
+}
+
+#[cfg(test)]
+mod from_pnm_bytes {
+    use super::*;
+
+    #[test]
+    fn it_should_read_back_every_format() {
+        let image = new_test_image();
+        let opaque_image = Image::new_from_vec2d(image.pixels().map(|c| c.replace_alpha_u32(255)));
+
+        for format in [PnmFormat::PpmAscii, PnmFormat::PpmBinary] {
+            let bytes = image.to_pnm_bytes(format);
+            assert_eq!(Image::from_pnm_bytes(&bytes), Ok(opaque_image.clone()));
+        }
+
+        let bytes = image.to_pnm_bytes(PnmFormat::Pam);
+        assert_eq!(Image::from_pnm_bytes(&bytes), Ok(image));
+    }
+
+    #[test]
+    fn it_should_skip_comments_and_scale_values() {
+        let bytes = b"P3 # comment\n# another comment\n1 1 15\n15 0 5\n";
+        let image = Image::from_pnm_bytes(bytes).unwrap();
+
+        assert_eq!(
+            image.get(Point(0, 0)),
+            Some(Colour::new_from_u8s(255, 0, 85, 255))
+        );
+    }
+
+    #[test]
+    fn it_should_read_sixteen_bit_ppm() {
+        let mut bytes = b"P6\n1 1\n65535\n".to_vec();
+        bytes.extend([0xff, 0xff, 0x00, 0x00, 0x80, 0x00]);
+        let image = Image::from_pnm_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            image.get(Point(0, 0)),
+            Some(Colour::new_from_u8s(255, 0, 128, 255))
+        );
+    }
+
+    #[test]
+    fn it_should_read_greyscale_alpha_pam() {
+        let mut bytes =
+            b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 2\nMAXVAL 255\nTUPLTYPE GRAYSCALE_ALPHA\nENDHDR\n"
+                .to_vec();
+        bytes.extend([100, 50]);
+        let image = Image::from_pnm_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            image.get(Point(0, 0)),
+            Some(Colour::new_from_u8s(100, 100, 100, 50))
+        );
+    }
+
+    #[test]
+    fn it_should_error_on_unknown_format() {
+        assert_eq!(
+            Image::from_pnm_bytes(b"P1\n1 1\n1\n"),
+            Err(ImageParseError::UnknownFormat)
+        );
+        assert_eq!(
+            Image::from_pnm_bytes(b""),
+            Err(ImageParseError::UnknownFormat)
+        );
+    }
+
+    #[test]
+    fn it_should_error_on_missing_pixels() {
+        assert_eq!(
+            Image::from_pnm_bytes(b"P6\n2 1\n255\n\x01\x02\x03"),
+            Err(ImageParseError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn it_should_error_on_huge_sizes_without_allocating() {
+        assert!(Image::from_pnm_bytes(b"P6\n18446744073709551615 2\n255\n").is_err());
+        assert!(Image::from_pnm_bytes(b"P6\n4000000000 4000000000\n255\n").is_err());
+        assert!(Image::from_pnm_bytes(b"P3\n100000 100000\n255\n0 0 0\n").is_err());
+        assert!(Image::from_pnm_bytes(
+            b"P7\nWIDTH 100000\nHEIGHT 100000\nDEPTH 4\nMAXVAL 65535\nENDHDR\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_should_error_on_max_value_too_large_for_u32() {
+        assert_eq!(
+            Image::from_pnm_bytes(b"P3\n1 1\n4294967551\n0 0 0\n"),
+            Err(ImageParseError::InvalidHeader)
+        );
+        assert_eq!(
+            Image::from_pnm_bytes(
+                b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 1\nMAXVAL 4294967551\nENDHDR\n\x00"
+            ),
+            Err(ImageParseError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn it_should_error_on_values_above_max() {
+        assert_eq!(
+            Image::from_pnm_bytes(b"P3\n1 1\n10\n11 0 0\n"),
+            Err(ImageParseError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn it_should_error_on_bad_header() {
+        assert_eq!(
+            Image::from_pnm_bytes(b"P3\n1 x\n255\n0 0 0\n"),
+            Err(ImageParseError::InvalidHeader)
+        );
+        assert_eq!(
+            Image::from_pnm_bytes(b"P7\nWIDTH 1\nHEIGHT 1\nMAXVAL 255\nENDHDR\n\x00"),
+            Err(ImageParseError::InvalidHeader)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+/// The Netpbm formats an `Image` can be saved as.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PnmFormat {
+    /// A PPM stored as text (`P3`).
+    /// Alpha is not saved.
+    PpmAscii,
+
+    /// A PPM stored as bytes (`P6`).
+    /// Alpha is not saved.
+    PpmBinary,
+
+    /// A PAM with the `RGB_ALPHA` tuple type (`P7`).
+    Pam,
+}
@@ -8,15 +8,24 @@
 #![warn(dead_code)]
 
 mod colour;
+mod image;
+mod palette;
 mod random;
 mod vec2d;
 
 pub mod geom;
 pub mod num;
 
-pub use self::colour::{Color, Colour};
+pub use self::colour::{
+    BlendMode, Color, Colour, ColourF32, ColourParseError, ColourSpace, Easing, Gradient,
+    GradientSamplesIterator, GradientSpread, Hsla, Hsva, Lab, LinearRgba, Oklab, Oklch,
+    PixelFormat, Rgba, TextSize, WcagLevel,
+};
+pub use self::image::*;
+pub use self::palette::*;
 pub use self::random::random;
 pub use self::random::Random;
+pub use self::random::Rng;
 pub use self::vec2d::*;
 
 #[macro_use]
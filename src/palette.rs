@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use crate::Colour;
+use crate::ColourSpace;
+
+mod dithering;
+
+mod gpl;
+
+mod jasc_pal;
+
+mod median_cut;
+
+mod palette_parse_error;
+pub use self::palette_parse_error::*;
+
+/// A limited list of colours, such as for a retro art style.
+///
+/// Colours can optionally be given names, to look them up by.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Palette {
+    colours: Vec<Colour>,
+    names: HashMap<String, usize>,
+}
+
+impl Palette {
+    pub fn new(colours: Vec<Colour>) -> Self {
+        Self {
+            colours,
+            names: HashMap::new(),
+        }
+    }
+
+    /// Adds a colour to the end, returning its index.
+    pub fn add(&mut self, colour: Colour) -> usize {
+        self.colours.push(colour);
+        self.colours.len() - 1
+    }
+
+    /// Adds a colour to the end, which can be found by the name given.
+    /// If the name is already in use, it is moved to this colour.
+    pub fn add_named(&mut self, name: &str, colour: Colour) -> usize {
+        let index = self.add(colour);
+        self.names.insert(name.to_string(), index);
+        index
+    }
+
+    pub fn get(&self, index: usize) -> Option<Colour> {
+        self.colours.get(index).copied()
+    }
+
+    pub fn get_named(&self, name: &str) -> Option<Colour> {
+        self.names.get(name).and_then(|index| self.get(*index))
+    }
+
+    /// Returns the name of the colour at the index, if it has one.
+    pub fn name_of(&self, index: usize) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(_, name_index)| **name_index == index)
+            .map(|(name, _)| name.as_str())
+    }
+
+    pub fn colours(&self) -> &[Colour] {
+        &self.colours
+    }
+
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    /// Returns the index of the closest colour,
+    /// measuring the distance within the colour space given.
+    ///
+    /// Returns None if this Palette is empty.
+    pub fn nearest_index(&self, colour: Colour, space: ColourSpace) -> Option<usize> {
+        let target = to_coordinates(colour, space);
+
+        self.colours
+            .iter()
+            .map(|palette_colour| distance_squared(target, to_coordinates(*palette_colour, space)))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the closest colour,
+    /// measuring the distance within the colour space given.
+    ///
+    /// Returns None if this Palette is empty.
+    pub fn nearest(&self, colour: Colour, space: ColourSpace) -> Option<Colour> {
+        self.nearest_index(colour, space)
+            .map(|index| self.colours[index])
+    }
+}
+
+impl From<Vec<Colour>> for Palette {
+    fn from(colours: Vec<Colour>) -> Self {
+        Self::new(colours)
+    }
+}
+
+/// Places the colour within the space, so distances can be compared.
+/// Oklch uses the Oklab position, as the hue is an angle.
+fn to_coordinates(colour: Colour, space: ColourSpace) -> [f32; 3] {
+    match space {
+        ColourSpace::Srgb => [colour.red_f32(), colour.green_f32(), colour.blue_f32()],
+        ColourSpace::LinearSrgb => {
+            let linear = colour.to_linear_rgba();
+            [linear.red, linear.green, linear.blue]
+        }
+        ColourSpace::Oklab | ColourSpace::Oklch => {
+            let oklab = colour.to_oklab();
+            [oklab.l, oklab.a, oklab.b]
+        }
+        ColourSpace::Lab => {
+            let lab = colour.to_lab();
+            [lab.l, lab.a, lab.b]
+        }
+    }
+}
+
+/// Reads the next three values as red, green, and blue, from 0 to 255.
+fn parse_rgb<'a, I>(values: &mut I) -> Result<Colour, PaletteParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut next = || -> Result<u8, PaletteParseError> {
+        Ok(values
+            .next()
+            .ok_or(PaletteParseError::InvalidValue)?
+            .parse()?)
+    };
+
+    Ok(Colour::new_from_u8s(next()?, next()?, next()?, 255))
+}
+
+fn distance_squared(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+#[cfg(test)]
+fn new_test_palette() -> Palette {
+    Palette::new(vec![
+        Colour::BLACK,
+        Colour::WHITE,
+        Colour::RED,
+        Colour::BLUE,
+    ])
+}
+
+#[cfg(test)]
+mod add_named {
+    use super::*;
+
+    #[test]
+    fn it_should_find_colours_by_name() {
+        let mut palette = new_test_palette();
+        let index = palette.add_named("sky", Colour::LIGHT_BLUE);
+
+        assert_eq!(index, 4);
+        assert_eq!(palette.get_named("sky"), Some(Colour::LIGHT_BLUE));
+        assert_eq!(palette.name_of(4), Some("sky"));
+        assert_eq!(palette.name_of(0), None);
+        assert_eq!(palette.get_named("sea"), None);
+    }
+
+    #[test]
+    fn it_should_move_reused_names() {
+        let mut palette = Palette::default();
+        palette.add_named("main", Colour::RED);
+        palette.add_named("main", Colour::BLUE);
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.get_named("main"), Some(Colour::BLUE));
+        assert_eq!(palette.name_of(0), None);
+    }
+}
+
+#[cfg(test)]
+mod nearest {
+    use super::*;
+
+    #[test]
+    fn it_should_return_closest_colour() {
+        let palette = new_test_palette();
+
+        assert_eq!(
+            palette.nearest(Colour::DARK_RED, ColourSpace::Srgb),
+            Some(Colour::RED)
+        );
+        assert_eq!(
+            palette.nearest(Colour::DARK_GREY, ColourSpace::Srgb),
+            Some(Colour::BLACK)
+        );
+        assert_eq!(
+            palette.nearest_index(Colour::LIGHT_GREY, ColourSpace::Oklab),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn it_should_depend_on_colour_space() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        let grey = Colour::new_from_u8s(110, 110, 110, 255);
+
+        assert_eq!(
+            palette.nearest(grey, ColourSpace::Srgb),
+            Some(Colour::BLACK)
+        );
+        assert_eq!(
+            palette.nearest(grey, ColourSpace::Oklab),
+            Some(Colour::WHITE)
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_empty() {
+        assert_eq!(
+            Palette::default().nearest(Colour::RED, ColourSpace::Lab),
+            None
+        );
+    }
+}
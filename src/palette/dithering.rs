@@ -0,0 +1,185 @@
+use crate::geom::Point;
+use crate::Colour;
+use crate::ColourSpace;
+use crate::Vec2D;
+
+use super::Palette;
+
+/// Thresholds from 0 to 15, spread out so neighbouring values are far apart.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+impl Palette {
+    /// Replaces every pixel with the nearest colour in this Palette, without dithering.
+    ///
+    /// Does nothing if this Palette is empty.
+    pub fn remap(&self, pixels: &mut Vec2D<Colour>, space: ColourSpace) {
+        for (pixel, _) in pixels.iter_mut() {
+            if let Some(colour) = self.nearest(*pixel, space) {
+                *pixel = colour;
+            }
+        }
+    }
+
+    /// Replaces every pixel with a colour from this Palette,
+    /// using ordered dithering with a 4x4 Bayer matrix.
+    ///
+    /// Does nothing if this Palette is empty.
+    pub fn dither_ordered(&self, pixels: &mut Vec2D<Colour>, space: ColourSpace) {
+        // Roughly the distance between colours, if they were spread evenly.
+        let spread = 1.0 / (self.len() as f32).cbrt();
+
+        for (pixel, Point(x, y)) in pixels.iter_mut() {
+            let threshold = (BAYER_4X4[y % 4][x % 4] as f32 + 0.5) / 16.0 - 0.5;
+            let offset = threshold * spread;
+            let dithered = Colour::new_from_f32s_rounded(
+                pixel.red_f32() + offset,
+                pixel.green_f32() + offset,
+                pixel.blue_f32() + offset,
+                pixel.alpha_f32(),
+            );
+
+            if let Some(colour) = self.nearest(dithered, space) {
+                *pixel = colour;
+            }
+        }
+    }
+
+    /// Replaces every pixel with a colour from this Palette,
+    /// using Floyd–Steinberg error diffusion.
+    ///
+    /// Pixels are worked through from the top row down.
+    /// Does nothing if this Palette is empty.
+    pub fn dither_floyd_steinberg(&self, pixels: &mut Vec2D<Colour>, space: ColourSpace) {
+        if self.is_empty() {
+            return;
+        }
+
+        let (width, height) = (pixels.width(), pixels.height());
+        let mut errors = Vec2D::new(pixels.size(), [0.0_f32; 3]);
+
+        for y in (0..height).rev() {
+            for x in 0..width {
+                let pos = Point(x, y);
+                let pixel = pixels[pos];
+                let error = errors[pos];
+                let wanted = [
+                    pixel.red_f32() + error[0],
+                    pixel.green_f32() + error[1],
+                    pixel.blue_f32() + error[2],
+                ];
+
+                let dithered = Colour::new_from_f32s_rounded(
+                    wanted[0],
+                    wanted[1],
+                    wanted[2],
+                    pixel.alpha_f32(),
+                );
+                let Some(colour) = self.nearest(dithered, space) else {
+                    return;
+                };
+                pixels[pos] = colour;
+
+                let found = [colour.red_f32(), colour.green_f32(), colour.blue_f32()];
+                let mut spread_error = |x: Option<usize>, y: Option<usize>, amount: f32| {
+                    if let (Some(x), Some(y)) = (x, y) {
+                        if x < width {
+                            for i in 0..3 {
+                                errors[Point(x, y)][i] += (wanted[i] - found[i]) * amount;
+                            }
+                        }
+                    }
+                };
+
+                let below = y.checked_sub(1);
+                spread_error(Some(x + 1), Some(y), 7.0 / 16.0);
+                spread_error(x.checked_sub(1), below, 3.0 / 16.0);
+                spread_error(Some(x), below, 5.0 / 16.0);
+                spread_error(Some(x + 1), below, 1.0 / 16.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn new_grey_pixels() -> Vec2D<Colour> {
+    Vec2D::new(crate::geom::Size(4, 4), Colour::GREY)
+}
+
+#[cfg(test)]
+fn count_colour(pixels: &Vec2D<Colour>, colour: Colour) -> usize {
+    pixels.iter().filter(|(pixel, _)| *pixel == colour).count()
+}
+
+#[cfg(test)]
+mod remap {
+    use super::*;
+
+    #[test]
+    fn it_should_use_nearest_colours() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        let mut pixels = Vec2D::new_from_vecs(vec![vec![Colour::DARK_GREY, Colour::LIGHT_GREY]]);
+        palette.remap(&mut pixels, ColourSpace::Srgb);
+
+        assert_eq!(
+            pixels,
+            Vec2D::new_from_vecs(vec![vec![Colour::BLACK, Colour::WHITE]])
+        );
+    }
+
+    #[test]
+    fn it_should_do_nothing_with_empty_palette() {
+        let mut pixels = new_grey_pixels();
+        Palette::default().remap(&mut pixels, ColourSpace::Srgb);
+
+        assert_eq!(pixels, new_grey_pixels());
+    }
+}
+
+#[cfg(test)]
+mod dither_ordered {
+    use super::*;
+
+    #[test]
+    fn it_should_mix_black_and_white_for_grey() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        let mut pixels = new_grey_pixels();
+        palette.dither_ordered(&mut pixels, ColourSpace::Srgb);
+
+        assert_eq!(count_colour(&pixels, Colour::WHITE), 8);
+        assert_eq!(count_colour(&pixels, Colour::BLACK), 8);
+        assert_ne!(pixels[Point(0, 0)], pixels[Point(1, 0)]);
+    }
+
+    #[test]
+    fn it_should_keep_palette_colours() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        let mut pixels = Vec2D::new(crate::geom::Size(4, 4), Colour::WHITE);
+        palette.dither_ordered(&mut pixels, ColourSpace::Srgb);
+
+        assert_eq!(count_colour(&pixels, Colour::WHITE), 16);
+    }
+}
+
+#[cfg(test)]
+mod dither_floyd_steinberg {
+    use super::*;
+
+    #[test]
+    fn it_should_mix_black_and_white_for_grey() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        let mut pixels = new_grey_pixels();
+        palette.dither_floyd_steinberg(&mut pixels, ColourSpace::Srgb);
+
+        assert_eq!(count_colour(&pixels, Colour::WHITE), 8);
+        assert_eq!(count_colour(&pixels, Colour::BLACK), 8);
+    }
+
+    #[test]
+    fn it_should_keep_palette_colours() {
+        let palette = Palette::new(vec![Colour::RED, Colour::BLUE]);
+        let mut pixels = Vec2D::new(crate::geom::Size(3, 3), Colour::BLUE);
+        palette.dither_floyd_steinberg(&mut pixels, ColourSpace::Oklab);
+
+        assert_eq!(count_colour(&pixels, Colour::BLUE), 9);
+    }
+}
@@ -0,0 +1,124 @@
+use super::parse_rgb;
+use super::Palette;
+use super::PaletteParseError;
+
+const GPL_HEADER: &str = "GIMP Palette";
+
+/// The name GIMP gives to colours without one.
+const GPL_UNTITLED: &str = "Untitled";
+
+impl Palette {
+    /// Writes this Palette as a GIMP `.gpl` file, including the colour names.
+    ///
+    /// Alpha is not supported by the format, and is dropped.
+    pub fn to_gpl_string(&self) -> String {
+        let mut gpl = format!("{}\n#\n", GPL_HEADER);
+
+        for (index, colour) in self.colours.iter().enumerate() {
+            gpl += &format!(
+                "{:3} {:3} {:3}\t{}\n",
+                colour.red_u8(),
+                colour.green_u8(),
+                colour.blue_u8(),
+                self.name_of(index).unwrap_or(GPL_UNTITLED)
+            );
+        }
+
+        gpl
+    }
+
+    /// Reads a GIMP `.gpl` file.
+    ///
+    /// Colour names are kept, so they can be found with `get_named`.
+    pub fn from_gpl_str(gpl: &str) -> Result<Self, PaletteParseError> {
+        let mut lines = gpl.lines();
+        if lines.next().map(str::trim) != Some(GPL_HEADER) {
+            return Err(PaletteParseError::UnknownFormat);
+        }
+
+        let mut palette = Palette::default();
+        for line in lines.map(str::trim) {
+            // Skips comments, blank lines, and headers such as `Name: ...`.
+            if !line.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+
+            let mut values = line.split_whitespace();
+            let colour = parse_rgb(&mut values)?;
+
+            // The rest of the line is the name, which may contain spaces.
+            let name = values.collect::<Vec<_>>().join(" ");
+            match name.as_str() {
+                "" | GPL_UNTITLED => palette.add(colour),
+                name => palette.add_named(name, colour),
+            };
+        }
+
+        Ok(palette)
+    }
+}
+
+#[cfg(test)]
+mod to_gpl_string {
+    use super::*;
+    use crate::Colour;
+
+    #[test]
+    fn it_should_write_colours_and_names() {
+        let mut palette = Palette::new(vec![Colour::RED]);
+        palette.add_named("Sky Blue", Colour::new_from_u8s(10, 200, 255, 255));
+
+        assert_eq!(
+            palette.to_gpl_string(),
+            "GIMP Palette\n#\n255   0   0\tUntitled\n 10 200 255\tSky Blue\n"
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip() {
+        let mut palette = Palette::new(vec![Colour::BLACK, Colour::WHITE]);
+        palette.add_named("Fire Red", Colour::ORANGE_RED);
+
+        assert_eq!(Palette::from_gpl_str(&palette.to_gpl_string()), Ok(palette));
+    }
+}
+
+#[cfg(test)]
+mod from_gpl_str {
+    use super::*;
+    use crate::Colour;
+
+    #[test]
+    fn it_should_read_gimp_palette() {
+        let gpl = "GIMP Palette\nName: Test\nColumns: 2\n#\n# A comment\n  0   0   0\tBlack Night\n255 128 0 Orange\n\n0 0 255\n";
+        let palette = Palette::from_gpl_str(gpl).unwrap();
+
+        assert_eq!(
+            palette.colours(),
+            &[
+                Colour::BLACK,
+                Colour::new_from_u8s(255, 128, 0, 255),
+                Colour::BLUE
+            ]
+        );
+        assert_eq!(palette.get_named("Black Night"), Some(Colour::BLACK));
+        assert_eq!(palette.name_of(1), Some("Orange"));
+        assert_eq!(palette.name_of(2), None);
+    }
+
+    #[test]
+    fn it_should_reject_other_files() {
+        assert_eq!(
+            Palette::from_gpl_str("JASC-PAL\n"),
+            Err(PaletteParseError::UnknownFormat)
+        );
+        assert_eq!(
+            Palette::from_gpl_str("GIMP Palette\n0 0 256\n"),
+            Err(PaletteParseError::InvalidValue)
+        );
+        assert_eq!(
+            Palette::from_gpl_str("GIMP Palette\n0 0\n"),
+            Err(PaletteParseError::InvalidValue)
+        );
+    }
+}
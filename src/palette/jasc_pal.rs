@@ -0,0 +1,114 @@
+use super::parse_rgb;
+use super::Palette;
+use super::PaletteParseError;
+
+const JASC_HEADER: &str = "JASC-PAL";
+const JASC_VERSION: &str = "0100";
+
+impl Palette {
+    /// Writes this Palette as a JASC (Paint Shop Pro) `.pal` file.
+    ///
+    /// Names and alpha are not supported by the format, and are dropped.
+    pub fn to_jasc_pal_string(&self) -> String {
+        let mut pal = format!("{}\r\n{}\r\n{}\r\n", JASC_HEADER, JASC_VERSION, self.len());
+
+        for colour in &self.colours {
+            pal += &format!(
+                "{} {} {}\r\n",
+                colour.red_u8(),
+                colour.green_u8(),
+                colour.blue_u8()
+            );
+        }
+
+        pal
+    }
+
+    /// Reads a JASC (Paint Shop Pro) `.pal` file.
+    pub fn from_jasc_pal_str(pal: &str) -> Result<Self, PaletteParseError> {
+        let mut lines = pal.lines().map(str::trim);
+        if lines.next() != Some(JASC_HEADER) {
+            return Err(PaletteParseError::UnknownFormat);
+        }
+
+        if lines.next() != Some(JASC_VERSION) {
+            return Err(PaletteParseError::InvalidHeader);
+        }
+
+        let num_colours: usize = lines
+            .next()
+            .and_then(|line| line.parse().ok())
+            .ok_or(PaletteParseError::InvalidHeader)?;
+
+        let mut palette = Palette::default();
+        for _ in 0..num_colours {
+            let line = lines.next().ok_or(PaletteParseError::UnexpectedEnd)?;
+            palette.add(parse_rgb(&mut line.split_whitespace())?);
+        }
+
+        Ok(palette)
+    }
+}
+
+#[cfg(test)]
+mod to_jasc_pal_string {
+    use super::*;
+    use crate::Colour;
+
+    #[test]
+    fn it_should_write_colours() {
+        let palette = Palette::new(vec![Colour::RED, Colour::new_from_u8s(1, 2, 3, 4)]);
+
+        assert_eq!(
+            palette.to_jasc_pal_string(),
+            "JASC-PAL\r\n0100\r\n2\r\n255 0 0\r\n1 2 3\r\n"
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip() {
+        let palette = Palette::new(vec![Colour::BLACK, Colour::ORANGE, Colour::CYAN]);
+
+        assert_eq!(
+            Palette::from_jasc_pal_str(&palette.to_jasc_pal_string()),
+            Ok(palette)
+        );
+    }
+}
+
+#[cfg(test)]
+mod from_jasc_pal_str {
+    use super::*;
+    use crate::Colour;
+
+    #[test]
+    fn it_should_read_unix_line_endings() {
+        let palette = Palette::from_jasc_pal_str("JASC-PAL\n0100\n1\n0 0 255\n").unwrap();
+
+        assert_eq!(palette.colours(), &[Colour::BLUE]);
+    }
+
+    #[test]
+    fn it_should_reject_bad_files() {
+        assert_eq!(
+            Palette::from_jasc_pal_str("GIMP Palette\n"),
+            Err(PaletteParseError::UnknownFormat)
+        );
+        assert_eq!(
+            Palette::from_jasc_pal_str("JASC-PAL\n0200\n1\n0 0 0\n"),
+            Err(PaletteParseError::InvalidHeader)
+        );
+        assert_eq!(
+            Palette::from_jasc_pal_str("JASC-PAL\n0100\nlots\n"),
+            Err(PaletteParseError::InvalidHeader)
+        );
+        assert_eq!(
+            Palette::from_jasc_pal_str("JASC-PAL\n0100\n2\n0 0 0\n"),
+            Err(PaletteParseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Palette::from_jasc_pal_str("JASC-PAL\n0100\n1\n0 x 0\n"),
+            Err(PaletteParseError::InvalidValue)
+        );
+    }
+}
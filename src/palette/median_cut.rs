@@ -0,0 +1,178 @@
+use crate::Colour;
+use crate::Vec2D;
+
+use super::Palette;
+
+impl Palette {
+    /// Builds a Palette of up to `num_colours` which best represents the pixels,
+    /// using median cut quantisation.
+    ///
+    /// There will be fewer colours if the pixels have fewer unique colours.
+    pub fn new_from_median_cut(pixels: &Vec2D<Colour>, num_colours: usize) -> Self {
+        if num_colours == 0 || pixels.raw_data().is_empty() {
+            return Self::default();
+        }
+
+        let mut boxes = vec![pixels.raw_data().to_vec()];
+        while boxes.len() < num_colours {
+            // Splits the box which has the widest range in a single channel.
+            let widest = boxes
+                .iter()
+                .map(|colour_box| widest_channel(colour_box))
+                .enumerate()
+                .max_by_key(|(_, (_, range))| *range);
+
+            let Some((index, (channel, range))) = widest else {
+                break;
+            };
+            if range == 0 {
+                break;
+            }
+
+            let mut colour_box = boxes.swap_remove(index);
+            colour_box.sort_unstable_by_key(|colour| channel_u8(*colour, channel));
+            let split_index = median_split_index(&colour_box, channel);
+            let upper_box = colour_box.split_off(split_index);
+
+            boxes.push(colour_box);
+            boxes.push(upper_box);
+        }
+
+        Self::new(boxes.iter().map(|colour_box| average(colour_box)).collect())
+    }
+}
+
+/// Returns red, green, or blue, for the channels 0, 1, and 2.
+fn channel_u8(colour: Colour, channel: usize) -> u8 {
+    match channel {
+        0 => colour.red_u8(),
+        1 => colour.green_u8(),
+        _ => colour.blue_u8(),
+    }
+}
+
+/// Returns where to split the sorted colours, as close to the middle as possible.
+///
+/// Colours with the same value are kept in the same half,
+/// so a run of one colour is not split into two boxes of that colour.
+/// The colours must have more than one value in the channel.
+fn median_split_index(colours: &[Colour], channel: usize) -> usize {
+    let median = channel_u8(colours[colours.len() / 2], channel);
+    let before = colours.partition_point(|colour| channel_u8(*colour, channel) < median);
+    let after = colours.partition_point(|colour| channel_u8(*colour, channel) <= median);
+
+    if before == 0 {
+        after
+    } else if after == colours.len() || colours.len() / 2 - before <= after - colours.len() / 2 {
+        before
+    } else {
+        after
+    }
+}
+
+/// Returns the channel with the widest range of values, and that range.
+fn widest_channel(colours: &[Colour]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let values = colours.iter().map(|colour| channel_u8(*colour, channel));
+            let min = values.clone().min().unwrap_or(0);
+            let max = values.max().unwrap_or(0);
+
+            (channel, max - min)
+        })
+        .max_by_key(|(_, range)| *range)
+        .unwrap_or((0, 0))
+}
+
+fn average(colours: &[Colour]) -> Colour {
+    let len = colours.len().max(1) as f32;
+    let sum = |value: fn(&Colour) -> f32| colours.iter().map(value).sum::<f32>() / len;
+
+    Colour::new_from_f32s_rounded(
+        sum(Colour::red_f32),
+        sum(Colour::green_f32),
+        sum(Colour::blue_f32),
+        sum(Colour::alpha_f32),
+    )
+}
+
+#[cfg(test)]
+mod new_from_median_cut {
+    use super::*;
+
+    fn sorted_colours(palette: &Palette) -> Vec<u32> {
+        let mut colours: Vec<u32> = palette.colours().iter().map(|c| c.to_rgba_u32()).collect();
+        colours.sort();
+        colours
+    }
+
+    #[test]
+    fn it_should_keep_colours_when_there_are_few() {
+        let pixels = Vec2D::new_from_vecs(vec![
+            vec![Colour::RED, Colour::BLUE],
+            vec![Colour::BLUE, Colour::RED],
+        ]);
+        let palette = Palette::new_from_median_cut(&pixels, 4);
+
+        assert_eq!(
+            sorted_colours(&palette),
+            vec![Colour::BLUE.to_rgba_u32(), Colour::RED.to_rgba_u32()]
+        );
+    }
+
+    #[test]
+    fn it_should_average_similar_colours() {
+        let pixels = Vec2D::new_from_vecs(vec![vec![
+            Colour::BLACK,
+            Colour::new_from_u8s(10, 10, 10, 255),
+            Colour::WHITE,
+            Colour::WHITE,
+        ]]);
+        let palette = Palette::new_from_median_cut(&pixels, 2);
+
+        assert_eq!(
+            sorted_colours(&palette),
+            vec![0x050505ff, Colour::WHITE.to_rgba_u32()]
+        );
+    }
+
+    #[test]
+    fn it_should_split_widest_channel_first() {
+        let pixels = Vec2D::new_from_vecs(vec![vec![
+            Colour::new_from_u8s(0, 0, 0, 255),
+            Colour::new_from_u8s(0, 10, 0, 255),
+            Colour::new_from_u8s(0, 0, 200, 255),
+            Colour::new_from_u8s(0, 10, 200, 255),
+        ]]);
+        let palette = Palette::new_from_median_cut(&pixels, 2);
+
+        assert_eq!(sorted_colours(&palette), vec![0x000500ff, 0x0005c8ff]);
+    }
+
+    #[test]
+    fn it_should_not_repeat_colours() {
+        let pixels = Vec2D::new_from_vecs(vec![vec![
+            Colour::RED,
+            Colour::RED,
+            Colour::RED,
+            Colour::BLUE,
+        ]]);
+        let palette = Palette::new_from_median_cut(&pixels, 3);
+        let expected = vec![Colour::BLUE.to_rgba_u32(), Colour::RED.to_rgba_u32()];
+
+        assert_eq!(sorted_colours(&palette), expected);
+
+        let mut row = vec![Colour::RED; 7];
+        row.push(Colour::BLUE);
+        let palette = Palette::new_from_median_cut(&Vec2D::new_from_vecs(vec![row]), 8);
+
+        assert_eq!(sorted_colours(&palette), expected);
+    }
+
+    #[test]
+    fn it_should_be_empty_for_no_colours() {
+        let pixels = Vec2D::new_from_vecs(vec![vec![Colour::RED]]);
+
+        assert!(Palette::new_from_median_cut(&pixels, 0).is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PaletteParseError {
+    /// The file does not start with the expected header.
+    UnknownFormat,
+
+    /// The header is missing values, or has values which are not supported.
+    InvalidHeader,
+
+    /// A colour value is not a number from 0 to 255.
+    InvalidValue,
+
+    /// The file ended before every colour was read.
+    UnexpectedEnd,
+}
+
+impl From<ParseIntError> for PaletteParseError {
+    fn from(_: ParseIntError) -> Self {
+        Self::InvalidValue
+    }
+}
+
+impl fmt::Display for PaletteParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat => write!(f, "unknown palette format"),
+            Self::InvalidHeader => write!(f, "invalid palette header"),
+            Self::InvalidValue => write!(f, "colour value is not a number from 0 to 255"),
+            Self::UnexpectedEnd => write!(f, "palette ended before every colour was read"),
+        }
+    }
+}
+
+impl Error for PaletteParseError {}
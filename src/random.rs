@@ -1,7 +1,4 @@
 use lazy_static::lazy_static;
-use rand::rngs::SmallRng;
-use rand::Rng;
-use rand::SeedableRng;
 use std::ops::Add;
 use std::ops::Range;
 use std::ops::Sub;
@@ -10,13 +7,17 @@ use std::sync::Mutex;
 use crate::geom::Line;
 use crate::geom::Point;
 
+mod rng;
+pub use self::rng::*;
+
 lazy_static! {
-    static ref GLOBAL_RNG: Mutex<SmallRng> = {
-        let rng = SmallRng::seed_from_u64(100);
-        Mutex::new(rng)
-    };
+    static ref GLOBAL_RNG: Mutex<Rng> = Mutex::new(Rng::from_seed(100));
 }
 
+/// A range of random numbers, which can be added to values, points, and lines.
+///
+/// The operators use a shared global generator.
+/// Use the `_with` methods to use your own `Rng` instead.
 #[derive(Copy, Clone)]
 pub struct Random {
     min: f32,
@@ -34,8 +35,29 @@ impl Random {
     pub fn random(&self) -> f32 {
         random_range(self.min..self.max)
     }
+
+    pub fn random_with(&self, rng: &mut Rng) -> f32 {
+        rng.f32_range(self.min..self.max)
+    }
+
+    /// Moves the point by a random amount on each axis.
+    pub fn offset_point_with(&self, rng: &mut Rng, Point(x, y): Point) -> Point {
+        Point(x + self.random_with(rng), y + self.random_with(rng))
+    }
+
+    /// Moves both ends of the line by a random amount on each axis.
+    pub fn offset_line_with(&self, rng: &mut Rng, Line(start, end): Line) -> Line {
+        Line(
+            self.offset_point_with(rng, start),
+            self.offset_point_with(rng, end),
+        )
+    }
 }
 
+/// Returns a random number within the range, using a shared global generator.
+/// The range can be backwards.
+///
+/// For reproducible numbers, use your own `Rng` instead.
 pub fn random(range: Range<f32>) -> f32 {
     let min = range.start.min(range.end);
     let max = range.start.max(range.end);
@@ -44,7 +66,7 @@ pub fn random(range: Range<f32>) -> f32 {
 }
 
 fn random_range(range: Range<f32>) -> f32 {
-    GLOBAL_RNG.lock().unwrap().gen_range(range)
+    GLOBAL_RNG.lock().unwrap().f32_range(range)
 }
 
 impl Add<f32> for Random {
@@ -94,3 +116,38 @@ impl Sub<Line<f32>> for Random {
         Line(self - start, self - end)
     }
 }
+
+#[cfg(test)]
+mod random_with {
+    use super::*;
+
+    #[test]
+    fn it_should_stay_within_range() {
+        let random = Random::new(5.0..-5.0);
+        let mut rng = Rng::from_seed(1);
+
+        for _ in 0..100 {
+            let n = random.random_with(&mut rng);
+            assert!((-5.0..5.0).contains(&n), "{}", n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod offset_line_with {
+    use super::*;
+
+    #[test]
+    fn it_should_be_reproducible() {
+        let random = Random::new(-1.0..1.0);
+        let line = Line(Point(0.0, 0.0), Point(10.0, 10.0));
+
+        let a = random.offset_line_with(&mut Rng::from_seed(3), line);
+        let b = random.offset_line_with(&mut Rng::from_seed(3), line);
+
+        assert_eq!(a, b);
+        assert_ne!(a, line);
+        assert!(a.start().hypot_to(line.start()) < 1.5);
+        assert!(a.end().hypot_to(line.end()) < 1.5);
+    }
+}
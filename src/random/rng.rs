@@ -0,0 +1,252 @@
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng as _;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use std::ops::Range;
+
+/// A seedable random number generator.
+///
+/// The same seed always gives the same numbers, on every platform,
+/// which makes it useful for tests and procedural generation.
+/// Numbers come from Xoshiro256++, rather than an algorithm picked per platform.
+/// Use `fork` to get independent streams, such as one for each thread.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    rng: Xoshiro256PlusPlus,
+}
+
+impl Rng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a new generator, seeded from this one.
+    ///
+    /// Its numbers are independent from this generator,
+    /// but forking from the same seed always gives the same result.
+    pub fn fork(&mut self) -> Self {
+        Self::from_seed(self.next_u64())
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.rng.gen()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.rng.gen()
+    }
+
+    /// Returns a number from 0.0 up to, but not including, 1.0.
+    pub fn f32(&mut self) -> f32 {
+        self.rng.gen()
+    }
+
+    /// Returns a number within the range.
+    /// The range can be backwards, and an empty range returns its start.
+    ///
+    /// Any finite range works, even one as wide as `f32::MIN..f32::MAX`.
+    pub fn f32_range(&mut self, range: Range<f32>) -> f32 {
+        let min = range.start.min(range.end);
+        let max = range.start.max(range.end);
+        if min >= max {
+            return range.start;
+        }
+
+        // Done in f64, as the distance between the ends can be too large for an f32.
+        let amount: f64 = self.rng.gen();
+        let n = (min as f64 + (max as f64 - min as f64) * amount) as f32;
+
+        // Rounding back to an f32 can land on the end, which is excluded.
+        if n >= max {
+            max.next_down()
+        } else {
+            n
+        }
+    }
+
+    /// Returns a number within the range.
+    /// Empty ranges return their start.
+    pub fn u32_range(&mut self, range: Range<u32>) -> u32 {
+        self.int_range(range)
+    }
+
+    /// Returns a number within the range.
+    /// Empty ranges return their start.
+    pub fn i32_range(&mut self, range: Range<i32>) -> i32 {
+        self.int_range(range)
+    }
+
+    /// Returns a number within the range.
+    /// Empty ranges return their start.
+    pub fn usize_range(&mut self, range: Range<usize>) -> usize {
+        self.int_range(range)
+    }
+
+    /// Returns a number within the range.
+    /// Empty ranges return their start.
+    pub fn isize_range(&mut self, range: Range<isize>) -> isize {
+        self.int_range(range)
+    }
+
+    /// Returns true with the probability given, from 0.0 (never) to 1.0 (always).
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.f32() < probability
+    }
+
+    fn int_range<T>(&mut self, range: Range<T>) -> T
+    where
+        T: SampleUniform + PartialOrd + Copy,
+    {
+        if range.start >= range.end {
+            return range.start;
+        }
+
+        self.rng.gen_range(range)
+    }
+}
+
+#[cfg(test)]
+mod from_seed {
+    use super::*;
+
+    #[test]
+    fn it_should_repeat_numbers_for_same_seed() {
+        let mut a = Rng::from_seed(123);
+        let mut b = Rng::from_seed(123);
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn it_should_give_different_numbers_for_different_seeds() {
+        let a: Vec<u32> = {
+            let mut rng = Rng::from_seed(1);
+            (0..4).map(|_| rng.next_u32()).collect()
+        };
+        let b: Vec<u32> = {
+            let mut rng = Rng::from_seed(2);
+            (0..4).map(|_| rng.next_u32()).collect()
+        };
+
+        assert_ne!(a, b);
+    }
+}
+
+#[cfg(test)]
+mod fork {
+    use super::*;
+
+    #[test]
+    fn it_should_be_independent_of_parent() {
+        let mut parent = Rng::from_seed(5);
+        let mut child = parent.fork();
+
+        let parent_numbers: Vec<u64> = (0..4).map(|_| parent.next_u64()).collect();
+        let child_numbers: Vec<u64> = (0..4).map(|_| child.next_u64()).collect();
+
+        assert_ne!(parent_numbers, child_numbers);
+    }
+
+    #[test]
+    fn it_should_be_reproducible() {
+        let mut a = Rng::from_seed(5).fork();
+        let mut b = Rng::from_seed(5).fork();
+
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod f32_range {
+    use super::*;
+
+    #[test]
+    fn it_should_stay_within_range() {
+        let mut rng = Rng::from_seed(9);
+
+        for _ in 0..100 {
+            let n = rng.f32_range(-2.0..3.0);
+            assert!((-2.0..3.0).contains(&n), "{}", n);
+
+            let n = rng.f32();
+            assert!((0.0..1.0).contains(&n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn it_should_allow_backwards_and_empty_ranges() {
+        let mut rng = Rng::from_seed(9);
+
+        for _ in 0..100 {
+            let n = rng.f32_range(3.0..-2.0);
+            assert!((-2.0..3.0).contains(&n), "{}", n);
+        }
+        assert_eq!(rng.f32_range(1.5..1.5), 1.5);
+    }
+
+    #[test]
+    fn it_should_allow_the_widest_range() {
+        let mut rng = Rng::from_seed(9);
+
+        for _ in 0..100 {
+            let n = rng.f32_range(f32::MIN..f32::MAX);
+            assert!((f32::MIN..f32::MAX).contains(&n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn it_should_exclude_the_end_of_tiny_ranges() {
+        let mut rng = Rng::from_seed(9);
+        let end = 1.0_f32.next_up();
+
+        for _ in 0..100 {
+            assert_eq!(rng.f32_range(1.0..end), 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod int_ranges {
+    use super::*;
+
+    #[test]
+    fn it_should_stay_within_range() {
+        let mut rng = Rng::from_seed(9);
+
+        for _ in 0..100 {
+            assert!((3..7).contains(&rng.u32_range(3..7)));
+            assert!((-7..-3).contains(&rng.i32_range(-7..-3)));
+            assert!((0..2).contains(&rng.usize_range(0..2)));
+            assert!((-1..1).contains(&rng.isize_range(-1..1)));
+        }
+    }
+
+    #[test]
+    fn it_should_return_start_of_empty_range() {
+        let mut rng = Rng::from_seed(9);
+
+        let (start, end) = (4, -4);
+
+        assert_eq!(rng.usize_range(4..4), 4);
+        assert_eq!(rng.i32_range(start..end), 4);
+    }
+}
+
+#[cfg(test)]
+mod chance {
+    use super::*;
+
+    #[test]
+    fn it_should_respect_never_and_always() {
+        let mut rng = Rng::from_seed(9);
+
+        for _ in 0..100 {
+            assert!(!rng.chance(0.0));
+            assert!(rng.chance(1.0));
+        }
+    }
+}
@@ -9,9 +9,32 @@ use crate::geom::Point;
 use crate::geom::Rect;
 use crate::geom::Size;
 
+mod connectivity;
+pub use self::connectivity::*;
+
+mod drawing;
+mod field_of_view;
+
+mod pathfinding;
+mod regions;
+pub use self::regions::*;
+
+mod resizing;
+mod transformations;
+
+mod vec2d_iter_mut;
+pub use self::vec2d_iter_mut::*;
+
+mod vec2d_view;
+pub use self::vec2d_view::*;
+
+mod vec2d_view_mut;
+pub use self::vec2d_view_mut::*;
+
 /// Holds the data for a Vec2D.
 ///
-/// A Vec2D has a fixed size and cannot be resized.
+/// A Vec2D has a fixed size, which only changes when explicitly resized,
+/// such as through `resize`, `crop`, or inserting and removing rows and columns.
 #[derive(Clone)]
 pub struct Vec2D<V: Copy> {
     /// The width of this.
@@ -96,10 +119,7 @@ impl<V: Copy> Vec2D<V> {
 
     /// Allows you to iterate over a sub section of this map.
     pub fn iter_of(&self, area: Rect<usize>) -> Vec2DIterator<V> {
-        let data_rect = self.rect();
-        let iterate_area = data_rect
-            .intersect_rect(area)
-            .unwrap_or(Rect(Point(0, 0), Size(0, 0)));
+        let iterate_area = self.clip_area(area);
 
         Vec2DIterator {
             data: &self.data,
@@ -110,6 +130,77 @@ impl<V: Copy> Vec2D<V> {
         }
     }
 
+    /// Returns a mutable iterator over the entire map.
+    pub fn iter_mut(&mut self) -> Vec2DIterMut<'_, V> {
+        self.iter_of_mut(self.rect())
+    }
+
+    /// Allows you to mutably iterate over a sub section of this map.
+    pub fn iter_of_mut(&mut self, area: Rect<usize>) -> Vec2DIterMut<'_, V> {
+        let iterate_area = self.clip_area(area);
+
+        Vec2DIterMut::new(&mut self.data, self.width, iterate_area)
+    }
+
+    /// Returns each row as a slice, starting from the bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[V]> {
+        let width = self.width;
+
+        (0..self.height).map(move |y| &self.data[y * width..(y + 1) * width])
+    }
+
+    /// Returns each row as a mutable slice, starting from the bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [V]> {
+        let width = self.width;
+        let mut remaining = self.data.as_mut_slice();
+
+        (0..self.height).map(move |_| {
+            let (row, rest) = std::mem::take(&mut remaining).split_at_mut(width);
+            remaining = rest;
+            row
+        })
+    }
+
+    /// Iterates over the column at the x position given, starting from the bottom.
+    ///
+    /// This is empty if x is outside of the map.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &V> {
+        self.data
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.column_height(x))
+    }
+
+    /// Mutably iterates over the column at the x position given, starting from the bottom.
+    ///
+    /// This is empty if x is outside of the map.
+    pub fn column_mut(&mut self, x: usize) -> impl Iterator<Item = &mut V> {
+        let column_height = self.column_height(x);
+
+        self.data
+            .iter_mut()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(column_height)
+    }
+
+    /// Returns a window onto a sub section of this map.
+    ///
+    /// The area is clipped to fit within the map.
+    pub fn view(&self, area: Rect<usize>) -> Vec2DView<'_, V> {
+        Vec2DView::new(self, self.clip_area(area))
+    }
+
+    /// Returns a mutable window onto a sub section of this map.
+    ///
+    /// The area is clipped to fit within the map.
+    pub fn view_mut(&mut self, area: Rect<usize>) -> Vec2DViewMut<'_, V> {
+        let view_area = self.clip_area(area);
+
+        Vec2DViewMut::new(self, view_area)
+    }
+
     /// Returns the underlying raw data.
     pub fn raw_data<'a>(&'a self) -> &'a [V] {
         &self.data
@@ -118,6 +209,43 @@ impl<V: Copy> Vec2D<V> {
     fn row<'a>(&'a self, y: usize) -> Vec2DRow<'a, V> {
         Vec2DRow::new(self, y)
     }
+
+    fn clip_area(&self, area: Rect<usize>) -> Rect<usize> {
+        self.rect()
+            .intersect_rect(area)
+            .unwrap_or(Rect(Point(0, 0), Size(0, 0)))
+    }
+
+    fn step_tile(&self, pos: Point<usize>, step: Point<isize>) -> Option<Point<usize>> {
+        let next = pos.to_signed_clamped() + step;
+
+        if self.is_signed_tile_in_bounds(next) {
+            Some(Point(next.x() as usize, next.y() as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the tile at the position, if it is inside.
+    fn signed_tile(&self, pos: Point<isize>) -> Option<Point<usize>> {
+        self.step_tile(Point(0, 0), pos)
+    }
+
+    fn is_tile_in_bounds(&self, Point(x, y): Point<usize>) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn is_signed_tile_in_bounds(&self, Point(x, y): Point<isize>) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn column_height(&self, x: usize) -> usize {
+        if x < self.width {
+            self.height
+        } else {
+            0
+        }
+    }
 }
 
 impl<V: Copy> Index<Point<usize>> for Vec2D<V> {
@@ -147,15 +275,14 @@ impl<'a, V: Copy> IntoIterator for &'a Vec2D<V> {
     }
 }
 
-// @TODO, This should be an IterMut.
-// impl<'a, V: Copy> IntoIterator for &'a mut Vec2D<V> {
-//     type Item = (V, Point<usize>);
-//     type IntoIter = Vec2DIterator<'a, V>;
+impl<'a, V: Copy> IntoIterator for &'a mut Vec2D<V> {
+    type Item = (&'a mut V, Point<usize>);
+    type IntoIter = Vec2DIterMut<'a, V>;
 
-//     fn into_iter(self) -> Self::IntoIter {
-//         self.iter()
-//     }
-// }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
 
 /// An iterator for the `Vec2D`.
 pub struct Vec2DIterator<'a, V: 'a> {
@@ -458,6 +585,130 @@ mod iterator {
     }
 }
 
+#[cfg(test)]
+mod iter_mut {
+    use super::*;
+
+    #[test]
+    fn it_should_set_every_tile() {
+        let mut vec2d = Vec2D::new(Size(4, 3), Point(0, 0));
+
+        for (tile, pos) in &mut vec2d {
+            *tile = pos;
+        }
+
+        for (tile, pos) in &vec2d {
+            assert_eq!(tile, pos);
+        }
+    }
+
+    #[test]
+    fn it_should_only_iterate_over_area_given() {
+        let mut vec2d = Vec2D::new(Size(4, 4), 0);
+
+        for (tile, _) in vec2d.iter_of_mut(Rect(Point(1, 1), Size(2, 2))) {
+            *tile = 1;
+        }
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0, 0],
+            vec![0, 1, 1, 0],
+            vec![0, 1, 1, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_clip_area_to_vec2d() {
+        let mut vec2d = Vec2D::new(Size(4, 4), 0);
+        let positions: Vec<Point<usize>> = vec2d
+            .iter_of_mut(Rect(Point(3, 2), Size(10, 10)))
+            .map(|(_, pos)| pos)
+            .collect();
+
+        assert_eq!(positions, vec![Point(3, 2), Point(3, 3)]);
+    }
+
+    #[test]
+    fn it_should_be_empty_when_area_is_outside() {
+        let mut vec2d = Vec2D::new(Size(4, 4), 0);
+
+        assert_eq!(vec2d.iter_of_mut(Rect(Point(5, 5), Size(2, 2))).count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod rows {
+    use super::*;
+
+    #[test]
+    fn it_should_return_rows_from_the_bottom() {
+        #[rustfmt::skip]
+        let vec2d = Vec2D::new_from_vecs(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+        ]);
+        let rows: Vec<&[i32]> = vec2d.rows().collect();
+
+        assert_eq!(rows, vec![&[1, 2, 3], &[4, 5, 6]]);
+    }
+
+    #[test]
+    fn it_should_return_empty_rows_when_no_width() {
+        let vec2d = Vec2D::<u32>::new(Size(0, 3), 0);
+
+        assert_eq!(vec2d.rows().count(), 3);
+    }
+
+    #[test]
+    fn it_should_allow_editing_rows() {
+        let mut vec2d = Vec2D::new(Size(3, 2), 0);
+
+        for (y, row) in vec2d.rows_mut().enumerate() {
+            row.fill(y);
+        }
+
+        assert_eq!(vec2d.raw_data(), &[0, 0, 0, 1, 1, 1]);
+    }
+}
+
+#[cfg(test)]
+mod column {
+    use super::*;
+
+    #[test]
+    fn it_should_return_column_from_the_bottom() {
+        #[rustfmt::skip]
+        let vec2d = Vec2D::new_from_vecs(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+        ]);
+        let column: Vec<i32> = vec2d.column(1).copied().collect();
+
+        assert_eq!(column, vec![2, 5]);
+    }
+
+    #[test]
+    fn it_should_be_empty_outside_of_vec2d() {
+        let vec2d = Vec2D::new(Size(3, 2), 0);
+
+        assert_eq!(vec2d.column(3).count(), 0);
+    }
+
+    #[test]
+    fn it_should_allow_editing_column() {
+        let mut vec2d = Vec2D::new(Size(3, 2), 0);
+
+        for tile in vec2d.column_mut(2) {
+            *tile = 1;
+        }
+
+        assert_eq!(vec2d.raw_data(), &[0, 0, 1, 0, 0, 1]);
+    }
+}
+
 #[cfg(test)]
 mod debug {
     use super::*;
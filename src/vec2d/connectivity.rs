@@ -0,0 +1,38 @@
+use crate::geom::Point;
+
+/// Which tiles count as being next to each other, on a grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only up, down, left, and right.
+    Four,
+
+    /// Up, down, left, right, and the diagonals.
+    Eight,
+}
+
+impl Connectivity {
+    /// The steps to get from a tile to each of its neighbours.
+    pub(crate) fn steps(self) -> &'static [Point<isize>] {
+        match self {
+            Connectivity::Four => &ORTHOGONAL_STEPS,
+            Connectivity::Eight => &ALL_STEPS,
+        }
+    }
+}
+
+pub(crate) const ORTHOGONAL_STEPS: [Point<isize>; 4] =
+    [Point(1, 0), Point(-1, 0), Point(0, 1), Point(0, -1)];
+
+pub(crate) const DIAGONAL_STEPS: [Point<isize>; 4] =
+    [Point(1, 1), Point(-1, 1), Point(1, -1), Point(-1, -1)];
+
+const ALL_STEPS: [Point<isize>; 8] = [
+    ORTHOGONAL_STEPS[0],
+    ORTHOGONAL_STEPS[1],
+    ORTHOGONAL_STEPS[2],
+    ORTHOGONAL_STEPS[3],
+    DIAGONAL_STEPS[0],
+    DIAGONAL_STEPS[1],
+    DIAGONAL_STEPS[2],
+    DIAGONAL_STEPS[3],
+];
@@ -0,0 +1,801 @@
+use crate::geom::BCurve;
+use crate::geom::Circle;
+use crate::geom::Line;
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+use crate::num::Num;
+use crate::num::ToRounded;
+
+use crate::geom::vector::closest_point_on_line;
+use crate::geom::vector::length;
+
+use super::Vec2D;
+
+impl<V: Copy> Vec2D<V> {
+    /// Draws the outline of a circle, using the midpoint circle algorithm.
+    ///
+    /// The centre and radius are rounded to the nearest tile.
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_circle<N: Num>(&mut self, circle: Circle<N>, value: V) {
+        let Circle(Point(centre_x, centre_y), radius) = rounded_circle(circle);
+        let Some((x_offsets, y_offsets)) = self.circle_offsets(centre_x, centre_y, radius) else {
+            return;
+        };
+
+        let mut draw_octant = |x: isize, y: isize| {
+            for (offset_x, offset_y) in [
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                let pos = Point(
+                    centre_x.saturating_add(offset_x),
+                    centre_y.saturating_add(offset_y),
+                );
+                self.set_clipped(pos, value);
+            }
+        };
+
+        // Rows reaching the canvas, and then columns reaching it once swapped.
+        for_each_circle_octant(radius, y_offsets, &mut draw_octant);
+        for_each_circle_octant(radius, x_offsets, |x, y| {
+            if !(y_offsets.0..=y_offsets.1).contains(&y) {
+                draw_octant(x, y);
+            }
+        });
+    }
+
+    /// Fills a circle, covering the same area as `draw_circle` and everything inside.
+    ///
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn fill_circle<N: Num>(&mut self, circle: Circle<N>, value: V) {
+        let Circle(Point(centre_x, centre_y), radius) = rounded_circle(circle);
+        let Some((_, y_offsets)) = self.circle_offsets(centre_x, centre_y, radius) else {
+            return;
+        };
+
+        let mut fill_rows = |half_width: isize, offset_y: isize| {
+            for offset_y in [offset_y, -offset_y] {
+                let y = centre_y.saturating_add(offset_y);
+                self.fill_clipped(
+                    Point(centre_x.saturating_sub(half_width), y),
+                    Point(centre_x.saturating_add(half_width), y),
+                    value,
+                );
+            }
+        };
+
+        // Rows the octant crosses directly, where the half width is x.
+        for_each_circle_octant(radius, y_offsets, &mut fill_rows);
+
+        // Rows the octant reaches once swapped, where the half width is y.
+        // Only the widest of these is needed for each row.
+        for offset_y in y_offsets.0..=y_offsets.1 {
+            if let Some(half_width) = widest_circle_octant_y(radius, offset_y) {
+                fill_rows(half_width, offset_y);
+            }
+        }
+    }
+
+    /// How far from the centre the tiles of this `Vec2D` are, along each axis.
+    ///
+    /// Returns None when the circle cannot reach any of them.
+    fn circle_offsets(
+        &self,
+        centre_x: isize,
+        centre_y: isize,
+        radius: isize,
+    ) -> Option<((isize, isize), (isize, isize))> {
+        let x_offsets = tile_offsets(centre_x, self.width())?;
+        let y_offsets = tile_offsets(centre_y, self.height())?;
+
+        if radius < 0 || x_offsets.0 > radius || y_offsets.0 > radius {
+            return None;
+        }
+
+        Some((x_offsets, y_offsets))
+    }
+
+    /// Draws the tiles along the edge of the rectangle.
+    ///
+    /// The rectangle is rounded to the nearest tiles,
+    /// and covers from the bottom left up to, but not including, the top right.
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_rect<N: Num>(&mut self, rect: Rect<N>, value: V) {
+        let Some((min, max)) = rounded_rect_tiles(rect) else {
+            return;
+        };
+
+        self.fill_clipped(min, Point(max.x(), min.y()), value);
+        self.fill_clipped(Point(min.x(), max.y()), max, value);
+        self.fill_clipped(min, Point(min.x(), max.y()), value);
+        self.fill_clipped(Point(max.x(), min.y()), max, value);
+    }
+
+    /// Fills every tile covered by the rectangle.
+    ///
+    /// The rectangle is rounded to the nearest tiles,
+    /// and covers from the bottom left up to, but not including, the top right.
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn fill_rect<N: Num>(&mut self, rect: Rect<N>, value: V) {
+        if let Some((min, max)) = rounded_rect_tiles(rect) {
+            self.fill_clipped(min, max, value);
+        }
+    }
+
+    /// Draws a one tile wide line, using Bresenham's line algorithm.
+    ///
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_line<N: Num>(&mut self, line: Line<N>, value: V) {
+        // Clipped first, so huge lines don't step through every tile outside.
+        let Some(line) = clip_line(line.to_f32(), self.size()) else {
+            return;
+        };
+
+        for pos in line.iter_cells_bresenham() {
+            self.set_clipped(pos, value);
+        }
+    }
+
+    /// Draws a line with rounded ends.
+    ///
+    /// Every tile whose centre is within half the thickness of the line is drawn.
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_thick_line<N: Num>(&mut self, line: Line<N>, thickness: N, value: V) {
+        let line = line.to_f32();
+        let thickness: f32 = thickness.to_rounded();
+        let half_thickness = thickness / 2.0;
+
+        let Line(start, end) = line;
+        let min = start.min(end) - Point(half_thickness, half_thickness);
+        let max = start.max(end) + Point(half_thickness, half_thickness);
+        let min: Point<isize> = Point(min.x().floor(), min.y().floor()).to_rounded();
+        let max: Point<isize> = Point(max.x().ceil(), max.y().ceil()).to_rounded();
+
+        let Some((min, max)) = self.clip_tiles(min, max) else {
+            return;
+        };
+
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                let centre = Point(x as f32, y as f32);
+                let closest = closest_point_on_line(line, centre);
+
+                if length(centre - closest) <= half_thickness {
+                    self.set(Point(x, y), value);
+                }
+            }
+        }
+    }
+
+    /// Draws a one tile wide Bézier curve.
+    ///
+    /// The curve is split into lines roughly one tile long,
+    /// which are each drawn using `draw_line`.
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_bcurve<const M: usize>(&mut self, curve: &BCurve<M>, value: V) {
+        for line in curve.iter_interpolation_lines(num_curve_segments(curve)) {
+            self.draw_line(line, value);
+        }
+    }
+
+    /// Draws a Bézier curve, where each part is drawn using `draw_thick_line`.
+    ///
+    /// Anything outside of this `Vec2D` is skipped.
+    pub fn draw_thick_bcurve<const M: usize>(
+        &mut self,
+        curve: &BCurve<M>,
+        thickness: f32,
+        value: V,
+    ) {
+        for line in curve.iter_interpolation_lines(num_curve_segments(curve)) {
+            self.draw_thick_line(line, thickness, value);
+        }
+    }
+
+    /// Sets the tile, if it is inside this `Vec2D`.
+    fn set_clipped(&mut self, pos: Point<isize>, value: V) {
+        if let Some(tile) = self.signed_tile(pos) {
+            self[tile] = value;
+        }
+    }
+
+    /// Sets every tile from the min to the max inclusive,
+    /// which is inside this `Vec2D`.
+    fn fill_clipped(&mut self, min: Point<isize>, max: Point<isize>, value: V) {
+        let Some((min, max)) = self.clip_tiles(min, max) else {
+            return;
+        };
+
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                self.set(Point(x, y), value);
+            }
+        }
+    }
+
+    /// Clamps the tiles from min to max inclusive, to those inside this `Vec2D`.
+    ///
+    /// Returns None if none of them are inside.
+    fn clip_tiles(
+        &self,
+        min: Point<isize>,
+        max: Point<isize>,
+    ) -> Option<(Point<usize>, Point<usize>)> {
+        if self.width() == 0 || self.height() == 0 {
+            return None;
+        }
+
+        let min = min.max(Point(0, 0));
+        let max = max.min(Point(self.width() as isize - 1, self.height() as isize - 1));
+
+        if max.x() < min.x() || max.y() < min.y() {
+            return None;
+        }
+
+        Some((
+            Point(min.x() as usize, min.y() as usize),
+            Point(max.x() as usize, max.y() as usize),
+        ))
+    }
+}
+
+fn rounded_circle<N: Num>(circle: Circle<N>) -> Circle<isize> {
+    circle.to_f32().to_rounded()
+}
+
+/// Returns the bottom left and top right tiles, inclusive.
+fn rounded_rect_tiles<N: Num>(rect: Rect<N>) -> Option<(Point<isize>, Point<isize>)> {
+    let rect = rect.to_f32();
+    let min: Point<isize> = rect.bottom_left().to_rounded();
+    let max: Point<isize> = rect.top_right().to_rounded();
+
+    if max.x() <= min.x() || max.y() <= min.y() {
+        return None;
+    }
+
+    Some((min, max - Point(1, 1)))
+}
+
+/// Clips the line to the tiles of a `Vec2D` of the size given, and rounds it.
+///
+/// This keeps a tile spare on each side, so rounding can't lose the edge tiles.
+/// It is done in f64, as huge lines lose too much precision in f32.
+fn clip_line(line: Line<f32>, size: Size<usize>) -> Option<Line<isize>> {
+    let Line(Point(start_x, start_y), Point(end_x, end_y)) = line;
+    let (start_x, start_y) = (start_x as f64, start_y as f64);
+    let (dx, dy) = (end_x as f64 - start_x, end_y as f64 - start_y);
+    let (width, height) = (size.width() as f64, size.height() as f64);
+
+    // Liang-Barsky, finding how far along the line it enters and leaves.
+    let mut enter: f64 = 0.0;
+    let mut leave: f64 = 1.0;
+    for (direction, distance) in [
+        (-dx, start_x + 1.0),
+        (dx, width - start_x),
+        (-dy, start_y + 1.0),
+        (dy, height - start_y),
+    ] {
+        if direction == 0.0 {
+            if distance < 0.0 {
+                return None;
+            }
+        } else if direction < 0.0 {
+            enter = enter.max(distance / direction);
+        } else {
+            leave = leave.min(distance / direction);
+        }
+    }
+
+    if enter > leave {
+        return None;
+    }
+
+    let point_at = |t: f64| {
+        Point(
+            (start_x + dx * t).round() as isize,
+            (start_y + dy * t).round() as isize,
+        )
+    };
+    Some(Line(point_at(enter), point_at(leave)))
+}
+
+/// Returns the nearest and furthest distances from the centre,
+/// to the tiles from 0 up to the length given.
+fn tile_offsets(centre: isize, len: usize) -> Option<(isize, isize)> {
+    if len == 0 {
+        return None;
+    }
+
+    let first = -(centre as i128);
+    let last = (len - 1) as i128 - centre as i128;
+    let nearest = if first <= 0 && 0 <= last {
+        0
+    } else {
+        first.abs().min(last.abs())
+    };
+    let furthest = first.abs().max(last.abs());
+    let to_isize = |n: i128| n.min(isize::MAX as i128) as isize;
+
+    Some((to_isize(nearest), to_isize(furthest)))
+}
+
+/// Steps around one eighth of a circle using the midpoint circle algorithm.
+///
+/// Calls the function with each position, relative to the centre,
+/// where `x >= y`. The other seven octants are found by swapping and negating these.
+/// Only positions where `y` is within the range given, inclusive, are included.
+///
+/// Each x is found directly, rather than stepping from the start,
+/// so huge circles only visit the part that is needed.
+fn for_each_circle_octant<F>(radius: isize, (min_y, max_y): (isize, isize), mut f: F)
+where
+    F: FnMut(isize, isize),
+{
+    if radius < 0 {
+        return;
+    }
+
+    let last_y = last_circle_octant_y(radius);
+    for y in min_y.max(0)..=max_y.min(last_y) {
+        if let Some(x) = circle_octant_x(radius, y) {
+            f(x, y);
+        }
+    }
+}
+
+/// The x the midpoint circle algorithm picks for the y given, where `x >= y`.
+///
+/// This is the largest x where `x * (x - 1) + y * y < radius * radius`.
+/// It is done in i128, so huge radiuses cannot overflow.
+fn circle_octant_x(radius: isize, y: isize) -> Option<isize> {
+    if radius == 0 && y == 0 {
+        return Some(0);
+    }
+
+    let (radius, y) = (radius as i128, y as i128);
+    let remaining = radius * radius - y * y;
+    if remaining <= 0 {
+        return None;
+    }
+
+    let mut x = remaining.isqrt() + 1;
+    while x * (x - 1) >= remaining {
+        x -= 1;
+    }
+    while (x + 1) * x < remaining {
+        x += 1;
+    }
+
+    if x < y {
+        None
+    } else {
+        Some(x as isize)
+    }
+}
+
+/// The last y in the octant, where x is still at least y.
+fn last_circle_octant_y(radius: isize) -> isize {
+    let mut y = ((radius as i128 * radius as i128) / 2).isqrt() as isize;
+    while y > 0 && circle_octant_x(radius, y).is_none() {
+        y -= 1;
+    }
+    while circle_octant_x(radius, y + 1).is_some() {
+        y += 1;
+    }
+
+    y
+}
+
+/// The widest y in the octant which has the x given.
+fn widest_circle_octant_y(radius: isize, x: isize) -> Option<isize> {
+    let (radius_i128, x_i128) = (radius as i128, x as i128);
+
+    // The x is at least the one given, for every y below this.
+    let remaining = radius_i128 * radius_i128 - x_i128 * (x_i128 - 1).max(0);
+    let y = if remaining > 0 {
+        ((remaining - 1).isqrt() as isize).min(last_circle_octant_y(radius))
+    } else {
+        0
+    };
+
+    (circle_octant_x(radius, y) == Some(x)).then_some(y)
+}
+
+/// Picks enough segments so that each is roughly one tile long.
+fn num_curve_segments<const M: usize>(curve: &BCurve<M>) -> u32 {
+    (curve.length().ceil() as u32).max(1)
+}
+
+#[cfg(test)]
+fn new_canvas(width: usize, height: usize) -> Vec2D<bool> {
+    Vec2D::new(crate::geom::Size(width, height), false)
+}
+
+#[cfg(test)]
+fn to_rows(canvas: &Vec2D<bool>) -> Vec<String> {
+    // Rows are listed top first, so they read like the picture.
+    (0..canvas.height())
+        .rev()
+        .map(|y| {
+            (0..canvas.width())
+                .map(|x| if canvas[Point(x, y)] { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod draw_circle {
+    use super::*;
+
+    #[test]
+    fn it_should_draw_outline() {
+        let mut canvas = new_canvas(7, 7);
+        canvas.draw_circle(Circle(Point(3, 3), 2), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".......",
+            "..###..",
+            ".#...#.",
+            ".#...#.",
+            ".#...#.",
+            "..###..",
+            ".......",
+        ]);
+    }
+
+    #[test]
+    fn it_should_leave_no_gaps() {
+        let mut canvas = new_canvas(41, 41);
+        canvas.draw_circle(Circle(Point(20.0, 20.0), 17.0), true);
+
+        for (is_drawn, pos) in &canvas {
+            if !is_drawn {
+                continue;
+            }
+
+            let neighbours = crate::vec2d::Connectivity::Eight
+                .steps()
+                .iter()
+                .filter_map(|step| canvas.step_tile(pos, *step))
+                .filter(|neighbour| canvas[*neighbour])
+                .count();
+            assert!(neighbours >= 2, "{:?}", pos);
+        }
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.draw_circle(Circle(Point(0, 0), 2), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "##.",
+            "..#",
+            "..#",
+        ]);
+    }
+
+    #[test]
+    fn it_should_skip_huge_circles_off_canvas() {
+        let mut canvas = new_canvas(10, 10);
+        canvas.draw_circle(Circle(Point(-4_000_000_000_isize, 0), 1_000_000_000), true);
+        canvas.draw_circle(Circle(Point(0_isize, 0), isize::MAX), true);
+
+        assert!(canvas.iter().all(|(is_drawn, _)| !is_drawn));
+    }
+
+    #[test]
+    fn it_should_draw_part_of_huge_circle_crossing_canvas() {
+        let mut canvas = new_canvas(6, 4);
+        canvas.draw_circle(Circle(Point(3_isize, -16_777_214), 16_777_216), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "......",
+            "######",
+            "......",
+            "......",
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod fill_circle {
+    use super::*;
+
+    #[test]
+    fn it_should_fill_inside() {
+        let mut canvas = new_canvas(7, 7);
+        canvas.fill_circle(Circle(Point(3, 3), 2), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".......",
+            "..###..",
+            ".#####.",
+            ".#####.",
+            ".#####.",
+            "..###..",
+            ".......",
+        ]);
+    }
+
+    #[test]
+    fn it_should_cover_outline() {
+        let circle = Circle(Point(10.0, 9.0), 7.4);
+        let mut outline = new_canvas(20, 20);
+        let mut filled = new_canvas(20, 20);
+        outline.draw_circle(circle, true);
+        filled.fill_circle(circle, true);
+
+        for (is_drawn, pos) in &outline {
+            if is_drawn {
+                assert!(filled[pos], "{:?}", pos);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_clip_when_centre_is_outside() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.fill_circle(Circle(Point(-1, 1), 2), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "##.",
+            "##.",
+            "##.",
+        ]);
+    }
+
+    #[test]
+    fn it_should_handle_huge_circles() {
+        let mut canvas = new_canvas(6, 4);
+        canvas.fill_circle(Circle(Point(-4_000_000_000_isize, 0), 1_000_000_000), true);
+        assert!(canvas.iter().all(|(is_drawn, _)| !is_drawn));
+
+        canvas.fill_circle(Circle(Point(3_isize, -16_777_214), 16_777_216), true);
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "......",
+            "######",
+            "######",
+            "######",
+        ]);
+
+        canvas.fill_circle(Circle(Point(0_isize, 0), isize::MAX), true);
+        assert!(canvas.iter().all(|(is_drawn, _)| is_drawn));
+    }
+}
+
+#[cfg(test)]
+mod draw_rect {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_draw_edges() {
+        let mut canvas = new_canvas(5, 4);
+        canvas.draw_rect(Rect(Point(1, 0), Size(4, 3)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".....",
+            ".####",
+            ".#..#",
+            ".####",
+        ]);
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.draw_rect(Rect(Point(-1.0, 1.0), Size(3.0, 5.0)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".#.",
+            "##.",
+            "...",
+        ]);
+    }
+
+    #[test]
+    fn it_should_draw_nothing_for_empty_rect() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.draw_rect(Rect(Point(1, 1), Size(0, 2)), true);
+
+        assert!(canvas.iter().all(|(is_drawn, _)| !is_drawn));
+    }
+}
+
+#[cfg(test)]
+mod fill_rect {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_fill_inside() {
+        let mut canvas = new_canvas(4, 3);
+        canvas.fill_rect(Rect(Point(1, 1), Size(2, 2)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".##.",
+            ".##.",
+            "....",
+        ]);
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut canvas = new_canvas(3, 2);
+        canvas.fill_rect(Rect(Point(2, -5), Size(10, 6)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "...",
+            "..#",
+        ]);
+    }
+
+    #[test]
+    fn it_should_do_nothing_on_empty_canvas() {
+        let mut canvas = new_canvas(5, 0);
+        canvas.fill_rect(Rect(Point(0, 0), Size(5, 5)), true);
+
+        assert_eq!(canvas.iter().count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod draw_line {
+    use super::*;
+
+    #[test]
+    fn it_should_draw_without_gaps() {
+        let mut canvas = new_canvas(5, 3);
+        canvas.draw_line(Line(Point(0.0, 0.0), Point(4.0, 2.0)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "...##",
+            ".##..",
+            "#....",
+        ]);
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.draw_line(Line(Point(-5, 1), Point(10, 1)), true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "...",
+            "###",
+            "...",
+        ]);
+    }
+
+    #[test]
+    fn it_should_clip_huge_lines_quickly() {
+        let mut canvas = new_canvas(10, 10);
+        canvas.draw_line(Line(Point(-2.0e8, -2.0e8), Point(2.0e8, 2.0e8)), true);
+
+        for i in 0..10 {
+            assert!(canvas[Point(i, i)], "{}", i);
+        }
+        assert_eq!(canvas.iter().filter(|(is_drawn, _)| *is_drawn).count(), 10);
+    }
+
+    #[test]
+    fn it_should_do_nothing_on_empty_canvas() {
+        let mut canvas = new_canvas(0, 0);
+        canvas.draw_line(Line(Point(-5, 1), Point(10, 1)), true);
+
+        assert_eq!(canvas.iter().count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod draw_thick_line {
+    use super::*;
+
+    #[test]
+    fn it_should_draw_with_thickness() {
+        let mut canvas = new_canvas(7, 5);
+        canvas.draw_thick_line(Line(Point(1, 2), Point(5, 2)), 2, true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            ".......",
+            ".#####.",
+            "#######",
+            ".#####.",
+            ".......",
+        ]);
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut canvas = new_canvas(3, 3);
+        canvas.draw_thick_line(Line(Point(-4.0, -4.0), Point(8.0, 8.0)), 1.0, true);
+
+        #[rustfmt::skip]
+        assert_eq!(to_rows(&canvas), vec![
+            "..#",
+            ".#.",
+            "#..",
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod draw_bcurve {
+    use super::*;
+
+    #[test]
+    fn it_should_draw_connected_curve() {
+        let mut canvas = new_canvas(21, 16);
+        let curve = BCurve::new_from_points([
+            Point(0.0, 0.0),
+            Point(5.0, 20.0),
+            Point(15.0, 20.0),
+            Point(20.0, 0.0),
+        ]);
+        canvas.draw_bcurve(&curve, true);
+
+        assert!(canvas[Point(0, 0)]);
+        assert!(canvas[Point(20, 0)]);
+
+        let components =
+            canvas.label_components(crate::vec2d::Connectivity::Eight, |_, is_drawn| is_drawn);
+        assert_eq!(components.len(), 1);
+    }
+
+    #[test]
+    fn it_should_draw_qubic_curve() {
+        let mut canvas = new_canvas(11, 11);
+        let curve = crate::geom::QubicBCurve::new_from_guide_lines(
+            Line(Point(0.0, 5.0), Point(3.0, 5.0)),
+            Line(Point(10.0, 5.0), Point(7.0, 5.0)),
+        );
+        canvas.draw_bcurve(&curve, true);
+
+        for x in 0..11 {
+            assert!(canvas[Point(x, 5)], "{}", x);
+        }
+        assert_eq!(canvas.iter().filter(|(is_drawn, _)| *is_drawn).count(), 11);
+    }
+}
+
+#[cfg(test)]
+mod draw_thick_bcurve {
+    use super::*;
+
+    #[test]
+    fn it_should_draw_curve_with_thickness() {
+        let mut canvas = new_canvas(11, 5);
+        let curve = BCurve::new_from_points([Point(0.0, 2.0), Point(5.0, 2.0), Point(10.0, 2.0)]);
+        canvas.draw_thick_bcurve(&curve, 3.0, true);
+
+        for x in 1..10 {
+            for y in 1..4 {
+                assert!(canvas[Point(x, y)], "{:?}", Point(x, y));
+            }
+        }
+        assert!(!canvas[Point(5, 0)]);
+        assert!(!canvas[Point(5, 4)]);
+    }
+}
@@ -0,0 +1,361 @@
+use crate::geom::Line;
+use crate::geom::Point;
+
+use super::Vec2D;
+
+impl<V: Copy> Vec2D<V> {
+    /// Works out which tiles can be seen from the origin,
+    /// using symmetric shadowcasting.
+    ///
+    /// The closure returns true for tiles which block sight, such as walls.
+    /// Opaque tiles are themselves visible, but hide whatever is behind them.
+    /// Tiles further than the radius away are never visible.
+    ///
+    /// This is symmetric, so if a floor tile `a` can see floor tile `b`,
+    /// then `b` can also see `a`.
+    pub fn field_of_view<F>(
+        &self,
+        origin: Point<usize>,
+        radius: usize,
+        mut is_opaque: F,
+    ) -> Vec2D<bool>
+    where
+        F: FnMut(Point<usize>, V) -> bool,
+    {
+        let mut visible = Vec2D::new(self.size(), false);
+        if !self.is_tile_in_bounds(origin) {
+            return visible;
+        }
+
+        visible[origin] = true;
+
+        let origin = origin.to_signed_clamped();
+
+        // Nothing is further away than the width plus the height,
+        // so larger radiuses are clamped to avoid overflowing when squared.
+        let radius = radius.min(self.width() + self.height()) as isize;
+
+        for quadrant in QUADRANTS {
+            let mut rows = vec![ShadowRow::new(1, Slope::new(-1, 1), Slope::new(1, 1))];
+
+            while let Some(mut row) = rows.pop() {
+                if row.depth > radius {
+                    continue;
+                }
+
+                let mut previous_is_wall = None;
+                for col in row.min_col()..=row.max_col() {
+                    let pos = quadrant.transform(origin, row.depth, col);
+                    let tile = self.signed_tile(pos);
+                    let is_wall = tile.is_none_or(|tile| is_opaque(tile, self[tile]));
+
+                    if let Some(tile) = tile {
+                        let is_in_radius = row.depth * row.depth + col * col <= radius * radius;
+                        if is_in_radius && (is_wall || row.is_symmetric(col)) {
+                            visible[tile] = true;
+                        }
+                    }
+
+                    if previous_is_wall == Some(true) && !is_wall {
+                        row.start_slope = Slope::for_tile(row.depth, col);
+                    }
+
+                    if previous_is_wall == Some(false) && is_wall {
+                        let mut next_row = row.next();
+                        next_row.end_slope = Slope::for_tile(row.depth, col);
+                        rows.push(next_row);
+                    }
+
+                    previous_is_wall = Some(is_wall);
+                }
+
+                if previous_is_wall == Some(false) {
+                    rows.push(row.next());
+                }
+            }
+        }
+
+        visible
+    }
+
+    /// Returns true if nothing opaque is in the way between the two tiles.
+    ///
+    /// The tiles at either end are not checked,
+    /// so it is possible to see a wall.
+    /// The path between them is stepped along using Bresenham's line algorithm.
+    pub fn has_line_of_sight<F>(
+        &self,
+        from: Point<usize>,
+        to: Point<usize>,
+        mut is_opaque: F,
+    ) -> bool
+    where
+        F: FnMut(Point<usize>, V) -> bool,
+    {
+        if !self.is_tile_in_bounds(from) || !self.is_tile_in_bounds(to) {
+            return false;
+        }
+
+        Line(from, to)
+            .iter_cells_bresenham()
+            .filter(|pos| *pos != from && *pos != to)
+            .all(|tile| !is_opaque(tile, self[tile]))
+    }
+}
+
+/// One of the four directions scanned out from the origin.
+#[derive(Copy, Clone, Debug)]
+enum Quadrant {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+const QUADRANTS: [Quadrant; 4] = [
+    Quadrant::Up,
+    Quadrant::Right,
+    Quadrant::Down,
+    Quadrant::Left,
+];
+
+impl Quadrant {
+    /// Turns a row depth and column, into a position on the map.
+    fn transform(self, Point(x, y): Point<isize>, depth: isize, col: isize) -> Point<isize> {
+        match self {
+            Quadrant::Up => Point(x + col, y + depth),
+            Quadrant::Right => Point(x + depth, y + col),
+            Quadrant::Down => Point(x + col, y - depth),
+            Quadrant::Left => Point(x - depth, y + col),
+        }
+    }
+}
+
+/// A fraction, so slopes are exact.
+/// The denominator is always positive.
+#[derive(Copy, Clone, Debug)]
+struct Slope {
+    numerator: isize,
+    denominator: isize,
+}
+
+impl Slope {
+    fn new(numerator: isize, denominator: isize) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// The slope to the edge of the tile, closest to the start of the row.
+    fn for_tile(depth: isize, col: isize) -> Self {
+        Self::new(2 * col - 1, 2 * depth)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ShadowRow {
+    depth: isize,
+    start_slope: Slope,
+    end_slope: Slope,
+}
+
+impl ShadowRow {
+    fn new(depth: isize, start_slope: Slope, end_slope: Slope) -> Self {
+        Self {
+            depth,
+            start_slope,
+            end_slope,
+        }
+    }
+
+    fn next(self) -> Self {
+        Self::new(self.depth + 1, self.start_slope, self.end_slope)
+    }
+
+    /// `depth * start_slope`, rounded with ties going up.
+    fn min_col(&self) -> isize {
+        let Slope {
+            numerator,
+            denominator,
+        } = self.start_slope;
+
+        (2 * self.depth * numerator + denominator).div_euclid(2 * denominator)
+    }
+
+    /// `depth * end_slope`, rounded with ties going down.
+    fn max_col(&self) -> isize {
+        let Slope {
+            numerator,
+            denominator,
+        } = self.end_slope;
+
+        -(denominator - 2 * self.depth * numerator).div_euclid(2 * denominator)
+    }
+
+    /// Floor tiles are only visible if their centre is within the row's slopes.
+    fn is_symmetric(&self, col: isize) -> bool {
+        col * self.start_slope.denominator >= self.depth * self.start_slope.numerator
+            && col * self.end_slope.denominator <= self.depth * self.end_slope.numerator
+    }
+}
+
+#[cfg(test)]
+fn walls_map(rows: Vec<&str>) -> Vec2D<bool> {
+    Vec2D::new_from_vecs(
+        rows.into_iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+fn is_wall(_: Point<usize>, is_wall: bool) -> bool {
+    is_wall
+}
+
+#[cfg(test)]
+mod test_field_of_view {
+    use super::*;
+
+    #[test]
+    fn it_should_see_everything_in_open_room() {
+        let map = walls_map(vec![".....", ".....", ".....", ".....", "....."]);
+        let visible = map.field_of_view(Point(2, 2), 10, is_wall);
+
+        assert!(visible.iter().all(|(is_visible, _)| is_visible));
+    }
+
+    #[test]
+    fn it_should_not_see_past_radius() {
+        let map = walls_map(vec![".......", ".......", "......."]);
+        let visible = map.field_of_view(Point(0, 1), 3, is_wall);
+
+        assert!(visible[Point(3, 1)]);
+        assert!(!visible[Point(4, 1)]);
+    }
+
+    #[test]
+    fn it_should_see_walls_but_not_behind_them() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            ".....",
+            ".....",
+            "..#..",
+            ".....",
+            ".....",
+        ]);
+        let visible = map.field_of_view(Point(2, 0), 10, is_wall);
+
+        assert!(visible[Point(2, 2)]);
+        assert!(!visible[Point(2, 3)]);
+        assert!(!visible[Point(2, 4)]);
+        assert!(visible[Point(0, 4)]);
+    }
+
+    #[test]
+    fn it_should_not_see_through_walls_into_other_rooms() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "...#...",
+            "...#...",
+            "...#...",
+        ]);
+        let visible = map.field_of_view(Point(0, 1), 10, is_wall);
+
+        assert!(visible[Point(3, 1)]);
+        for y in 0..3 {
+            for x in 4..7 {
+                assert!(!visible[Point(x, y)], "{:?}", Point(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_be_symmetric() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "..#....",
+            ".....#.",
+            "#..#...",
+            "....#..",
+            ".#.....",
+        ]);
+
+        for (a_is_wall, a) in &map {
+            if a_is_wall {
+                continue;
+            }
+
+            let from_a = map.field_of_view(a, 20, is_wall);
+            for (b_is_wall, b) in &map {
+                if b_is_wall {
+                    continue;
+                }
+
+                let from_b = map.field_of_view(b, 20, is_wall);
+                assert_eq!(from_a[b], from_b[a], "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_return_nothing_when_origin_is_outside() {
+        let map = walls_map(vec!["..."]);
+        let visible = map.field_of_view(Point(5, 5), 10, is_wall);
+
+        assert!(visible.iter().all(|(is_visible, _)| !is_visible));
+    }
+
+    #[test]
+    fn it_should_return_nothing_for_empty_maps() {
+        for size in [crate::geom::Size(0, 0), crate::geom::Size(0, 3)] {
+            let map = Vec2D::new(size, false);
+            let visible = map.field_of_view(Point(0, 0), 10, is_wall);
+
+            assert_eq!(visible.size(), size);
+        }
+    }
+
+    #[test]
+    fn it_should_handle_huge_radius() {
+        let map = walls_map(vec![".....", ".....", "....."]);
+
+        for radius in [usize::MAX, isize::MAX as usize] {
+            let visible = map.field_of_view(Point(0, 0), radius, is_wall);
+            assert!(visible.iter().all(|(is_visible, _)| is_visible));
+        }
+    }
+}
+
+#[cfg(test)]
+mod has_line_of_sight {
+    use super::*;
+
+    #[test]
+    fn it_should_see_across_open_room() {
+        let map = walls_map(vec!["......", "......", "......"]);
+
+        assert!(map.has_line_of_sight(Point(0, 0), Point(5, 2), is_wall));
+    }
+
+    #[test]
+    fn it_should_be_blocked_by_walls() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "......",
+            "..#...",
+            "......",
+        ]);
+
+        assert!(!map.has_line_of_sight(Point(0, 1), Point(5, 1), is_wall));
+        assert!(map.has_line_of_sight(Point(0, 0), Point(5, 0), is_wall));
+    }
+
+    #[test]
+    fn it_should_see_the_wall_at_the_end() {
+        let map = walls_map(vec!["...#"]);
+
+        assert!(map.has_line_of_sight(Point(0, 0), Point(3, 0), is_wall));
+    }
+}
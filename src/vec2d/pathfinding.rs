@@ -0,0 +1,750 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::f32::consts::SQRT_2;
+
+use crate::geom::Point;
+
+use super::Connectivity;
+use super::Vec2D;
+use super::DIAGONAL_STEPS;
+use super::ORTHOGONAL_STEPS;
+
+impl<V: Copy> Vec2D<V> {
+    /// Finds the cheapest path between two tiles, using A*.
+    ///
+    /// The cost function returns the cost of stepping onto a tile,
+    /// or `None` if the tile cannot be walked on.
+    /// Diagonal steps cost `SQRT_2` times as much, and cannot cut corners.
+    ///
+    /// The path returned includes both the start and the end.
+    /// Costs are expected to be at least 1, otherwise the path found may not be the cheapest.
+    pub fn find_path<F>(
+        &self,
+        from: Point<usize>,
+        to: Point<usize>,
+        connectivity: Connectivity,
+        mut cost: F,
+    ) -> Option<Vec<Point<usize>>>
+    where
+        F: FnMut(Point<usize>, V) -> Option<f32>,
+    {
+        if !self.is_tile_in_bounds(from) || !self.is_tile_in_bounds(to) {
+            return None;
+        }
+
+        // The end must be walkable.
+        cost(to, self[to])?;
+
+        let mut costs: Vec2D<Option<f32>> = Vec2D::new(self.size(), None);
+        let mut came_from: Vec2D<Option<Point<usize>>> = Vec2D::new(self.size(), None);
+        let mut open = BinaryHeap::new();
+
+        costs[from] = Some(0.0);
+        open.push(OpenTile::new(heuristic(from, to, connectivity), 0.0, from));
+
+        while let Some(tile) = open.pop() {
+            if tile.pos == to {
+                return Some(build_path(&came_from, to));
+            }
+
+            // Skip tiles which have since been reached more cheaply.
+            if costs[tile.pos].is_some_and(|pos_cost| tile.cost > pos_cost) {
+                continue;
+            }
+
+            for (next, step_cost) in self.neighbours(tile.pos, connectivity, &mut cost) {
+                let next_cost = tile.cost + step_cost;
+
+                if costs[next].is_none_or(|old_cost| next_cost < old_cost) {
+                    costs[next] = Some(next_cost);
+                    came_from[next] = Some(tile.pos);
+
+                    let priority = next_cost + heuristic(next, to, connectivity);
+                    open.push(OpenTile::new(priority, next_cost, next));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the cost of the cheapest path from every tile to the nearest target,
+    /// using Dijkstra's algorithm.
+    ///
+    /// The cost function works the same as for `find_path`.
+    /// Tiles which cannot reach a target are `None`.
+    pub fn distance_map<F>(
+        &self,
+        targets: &[Point<usize>],
+        connectivity: Connectivity,
+        mut cost: F,
+    ) -> Vec2D<Option<f32>>
+    where
+        F: FnMut(Point<usize>, V) -> Option<f32>,
+    {
+        let mut costs: Vec2D<Option<f32>> = Vec2D::new(self.size(), None);
+        let mut open = BinaryHeap::new();
+
+        for target in targets {
+            if self.is_tile_in_bounds(*target) {
+                costs[*target] = Some(0.0);
+                open.push(OpenTile::new(0.0, 0.0, *target));
+            }
+        }
+
+        while let Some(tile) = open.pop() {
+            if costs[tile.pos].is_some_and(|pos_cost| tile.cost > pos_cost) {
+                continue;
+            }
+
+            for (next, step_cost) in self.neighbours(tile.pos, connectivity, &mut cost) {
+                let next_cost = tile.cost + step_cost;
+
+                if costs[next].is_none_or(|old_cost| next_cost < old_cost) {
+                    costs[next] = Some(next_cost);
+                    open.push(OpenTile::new(next_cost, next_cost, next));
+                }
+            }
+        }
+
+        costs
+    }
+
+    /// Finds the shortest path between two tiles, using jump point search.
+    ///
+    /// This is much faster than `find_path` on large open maps,
+    /// but only works when every walkable tile costs the same.
+    /// It always uses `Connectivity::Eight`, and cannot cut corners.
+    ///
+    /// The path returned includes both the start and the end,
+    /// and every tile in between.
+    pub fn find_path_jps<F>(
+        &self,
+        from: Point<usize>,
+        to: Point<usize>,
+        is_walkable: F,
+    ) -> Option<Vec<Point<usize>>>
+    where
+        F: FnMut(Point<usize>, V) -> bool,
+    {
+        if !self.is_tile_in_bounds(from) || !self.is_tile_in_bounds(to) {
+            return None;
+        }
+
+        let mut search = JumpPointSearch {
+            map: self,
+            to: to.to_signed_clamped(),
+            is_walkable,
+        };
+
+        if !search.is_walkable(search.to) {
+            return None;
+        }
+
+        let mut costs: Vec2D<Option<f32>> = Vec2D::new(self.size(), None);
+        let mut came_from: Vec2D<Option<Point<usize>>> = Vec2D::new(self.size(), None);
+        let mut open = BinaryHeap::new();
+
+        costs[from] = Some(0.0);
+        open.push(OpenTile::new(octile_distance(from, to), 0.0, from));
+
+        while let Some(tile) = open.pop() {
+            if tile.pos == to {
+                return Some(fill_path(build_path(&came_from, to)));
+            }
+
+            if costs[tile.pos].is_some_and(|pos_cost| tile.cost > pos_cost) {
+                continue;
+            }
+
+            let parent = came_from[tile.pos];
+            for neighbour in search.neighbours(tile.pos, parent) {
+                let step = tile.pos.step_direction_to(neighbour);
+                let Some(jump_point) = search.jump(neighbour.to_signed_clamped(), step) else {
+                    continue;
+                };
+
+                let jump_point = to_unsigned(jump_point);
+                let next_cost = tile.cost + octile_distance(tile.pos, jump_point);
+                if costs[jump_point].is_none_or(|old_cost| next_cost < old_cost) {
+                    costs[jump_point] = Some(next_cost);
+                    came_from[jump_point] = Some(tile.pos);
+
+                    let priority = next_cost + octile_distance(jump_point, to);
+                    open.push(OpenTile::new(priority, next_cost, jump_point));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the walkable tiles next to the one given, along with the cost of stepping onto them.
+    fn neighbours<F>(
+        &self,
+        pos: Point<usize>,
+        connectivity: Connectivity,
+        cost: &mut F,
+    ) -> Vec<(Point<usize>, f32)>
+    where
+        F: FnMut(Point<usize>, V) -> Option<f32>,
+    {
+        let mut found = Vec::with_capacity(8);
+        let mut is_walkable = [false; 4];
+
+        for (i, step) in ORTHOGONAL_STEPS.into_iter().enumerate() {
+            let Some(next) = self.step_tile(pos, step) else {
+                continue;
+            };
+
+            if let Some(step_cost) = cost(next, self[next]) {
+                is_walkable[i] = true;
+                found.push((next, step_cost));
+            }
+        }
+
+        if connectivity == Connectivity::Eight {
+            for step in DIAGONAL_STEPS {
+                // Both tiles either side of the diagonal must be free.
+                let x_index = if step.x() > 0 { 0 } else { 1 };
+                let y_index = if step.y() > 0 { 2 } else { 3 };
+                if !is_walkable[x_index] || !is_walkable[y_index] {
+                    continue;
+                }
+
+                let Some(next) = self.step_tile(pos, step) else {
+                    continue;
+                };
+
+                if let Some(step_cost) = cost(next, self[next]) {
+                    found.push((next, step_cost * SQRT_2));
+                }
+            }
+        }
+
+        found
+    }
+}
+
+struct JumpPointSearch<'a, V: Copy, F> {
+    map: &'a Vec2D<V>,
+    to: Point<isize>,
+    is_walkable: F,
+}
+
+impl<'a, V: Copy, F> JumpPointSearch<'a, V, F>
+where
+    F: FnMut(Point<usize>, V) -> bool,
+{
+    fn is_walkable(&mut self, pos: Point<isize>) -> bool {
+        if !self.map.is_signed_tile_in_bounds(pos) {
+            return false;
+        }
+
+        let pos = to_unsigned(pos);
+        (self.is_walkable)(pos, self.map[pos])
+    }
+
+    fn is_walkable_at(&mut self, x: isize, y: isize) -> bool {
+        self.is_walkable(Point(x, y))
+    }
+
+    /// Steps from the position given until something interesting is found.
+    ///
+    /// Returns `None` if it runs into a wall first.
+    fn jump(&mut self, mut pos: Point<isize>, step: Point<isize>) -> Option<Point<isize>> {
+        let Point(dx, dy) = step;
+
+        loop {
+            if !self.is_walkable(pos) {
+                return None;
+            }
+
+            if pos == self.to {
+                return Some(pos);
+            }
+
+            let Point(x, y) = pos;
+            if dx != 0 && dy != 0 {
+                if self.jump(Point(x + dx, y), Point(dx, 0)).is_some()
+                    || self.jump(Point(x, y + dy), Point(0, dy)).is_some()
+                {
+                    return Some(pos);
+                }
+            } else if dx != 0 {
+                if (self.is_walkable_at(x, y - 1) && !self.is_walkable_at(x - dx, y - 1))
+                    || (self.is_walkable_at(x, y + 1) && !self.is_walkable_at(x - dx, y + 1))
+                {
+                    return Some(pos);
+                }
+            } else if (self.is_walkable_at(x - 1, y) && !self.is_walkable_at(x - 1, y - dy))
+                || (self.is_walkable_at(x + 1, y) && !self.is_walkable_at(x + 1, y - dy))
+            {
+                return Some(pos);
+            }
+
+            // Corners cannot be cut when moving diagonally.
+            if !self.is_walkable_at(x + dx, y) || !self.is_walkable_at(x, y + dy) {
+                return None;
+            }
+
+            pos = Point(x + dx, y + dy);
+        }
+    }
+
+    /// Returns the neighbours worth exploring,
+    /// based on the direction we arrived from.
+    fn neighbours(&mut self, pos: Point<usize>, parent: Option<Point<usize>>) -> Vec<Point<usize>> {
+        let Point(x, y) = pos.to_signed_clamped();
+        let mut found = Vec::with_capacity(8);
+
+        let Some(parent) = parent else {
+            for Point(dx, dy) in ORTHOGONAL_STEPS {
+                if self.is_walkable_at(x + dx, y + dy) {
+                    found.push(Point(x + dx, y + dy));
+                }
+            }
+
+            for Point(dx, dy) in DIAGONAL_STEPS {
+                if self.is_walkable_at(x + dx, y)
+                    && self.is_walkable_at(x, y + dy)
+                    && self.is_walkable_at(x + dx, y + dy)
+                {
+                    found.push(Point(x + dx, y + dy));
+                }
+            }
+
+            return found.into_iter().map(to_unsigned).collect();
+        };
+
+        let Point(dx, dy) = parent.step_direction_to(pos);
+        if dx != 0 && dy != 0 {
+            let is_next_y_walkable = self.is_walkable_at(x, y + dy);
+            let is_next_x_walkable = self.is_walkable_at(x + dx, y);
+
+            if is_next_y_walkable {
+                found.push(Point(x, y + dy));
+            }
+            if is_next_x_walkable {
+                found.push(Point(x + dx, y));
+            }
+            if is_next_y_walkable && is_next_x_walkable {
+                found.push(Point(x + dx, y + dy));
+            }
+        } else if dx != 0 {
+            let is_next_walkable = self.is_walkable_at(x + dx, y);
+            let is_above_walkable = self.is_walkable_at(x, y + 1);
+            let is_below_walkable = self.is_walkable_at(x, y - 1);
+
+            if is_next_walkable {
+                found.push(Point(x + dx, y));
+                if is_above_walkable {
+                    found.push(Point(x + dx, y + 1));
+                }
+                if is_below_walkable {
+                    found.push(Point(x + dx, y - 1));
+                }
+            }
+            if is_above_walkable {
+                found.push(Point(x, y + 1));
+            }
+            if is_below_walkable {
+                found.push(Point(x, y - 1));
+            }
+        } else {
+            let is_next_walkable = self.is_walkable_at(x, y + dy);
+            let is_right_walkable = self.is_walkable_at(x + 1, y);
+            let is_left_walkable = self.is_walkable_at(x - 1, y);
+
+            if is_next_walkable {
+                found.push(Point(x, y + dy));
+                if is_right_walkable {
+                    found.push(Point(x + 1, y + dy));
+                }
+                if is_left_walkable {
+                    found.push(Point(x - 1, y + dy));
+                }
+            }
+            if is_right_walkable {
+                found.push(Point(x + 1, y));
+            }
+            if is_left_walkable {
+                found.push(Point(x - 1, y));
+            }
+        }
+
+        found.into_iter().map(to_unsigned).collect()
+    }
+}
+
+/// An entry in the open list.
+/// These are ordered so the lowest priority comes out of a `BinaryHeap` first.
+#[derive(Copy, Clone, Debug)]
+struct OpenTile {
+    priority: f32,
+    cost: f32,
+    pos: Point<usize>,
+}
+
+impl OpenTile {
+    fn new(priority: f32, cost: f32, pos: Point<usize>) -> Self {
+        Self {
+            priority,
+            cost,
+            pos,
+        }
+    }
+}
+
+impl PartialEq for OpenTile {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenTile {}
+
+impl PartialOrd for OpenTile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenTile {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, as `BinaryHeap` is a max heap.
+        // On ties prefer the tile which is furthest along.
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then(self.cost.total_cmp(&other.cost))
+    }
+}
+
+fn heuristic(from: Point<usize>, to: Point<usize>, connectivity: Connectivity) -> f32 {
+    match connectivity {
+        Connectivity::Four => (from.x().abs_diff(to.x()) + from.y().abs_diff(to.y())) as f32,
+        Connectivity::Eight => octile_distance(from, to),
+    }
+}
+
+/// The distance when moving diagonally costs `SQRT_2`.
+fn octile_distance(from: Point<usize>, to: Point<usize>) -> f32 {
+    let x = from.x().abs_diff(to.x()) as f32;
+    let y = from.y().abs_diff(to.y()) as f32;
+
+    x.max(y) + (SQRT_2 - 1.0) * x.min(y)
+}
+
+fn build_path(came_from: &Vec2D<Option<Point<usize>>>, to: Point<usize>) -> Vec<Point<usize>> {
+    let mut path = vec![to];
+    let mut pos = to;
+
+    while let Some(previous) = came_from[pos] {
+        path.push(previous);
+        pos = previous;
+    }
+
+    path.reverse();
+    path
+}
+
+/// Fills in the tiles between each jump point.
+fn fill_path(jump_points: Vec<Point<usize>>) -> Vec<Point<usize>> {
+    let mut path = Vec::with_capacity(jump_points.len());
+
+    for pair in jump_points.windows(2) {
+        let step = pair[0].step_direction_to(pair[1]);
+        let mut pos = pair[0];
+
+        while pos != pair[1] {
+            path.push(pos);
+            pos = to_unsigned(pos.to_signed_clamped() + step);
+        }
+    }
+
+    path.extend(jump_points.last());
+    path
+}
+
+fn to_unsigned(Point(x, y): Point<isize>) -> Point<usize> {
+    Point(x as usize, y as usize)
+}
+
+#[cfg(test)]
+fn walls_map(rows: Vec<&str>) -> Vec2D<bool> {
+    Vec2D::new_from_vecs(
+        rows.into_iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+fn floor_cost(_: Point<usize>, is_wall: bool) -> Option<f32> {
+    if is_wall {
+        None
+    } else {
+        Some(1.0)
+    }
+}
+
+#[cfg(test)]
+fn assert_path_is_connected(path: &[Point<usize>], connectivity: Connectivity) {
+    for pair in path.windows(2) {
+        let x = pair[0].x().abs_diff(pair[1].x());
+        let y = pair[0].y().abs_diff(pair[1].y());
+
+        match connectivity {
+            Connectivity::Four => assert_eq!(x + y, 1, "{:?}", pair),
+            Connectivity::Eight => assert!(x.max(y) == 1, "{:?}", pair),
+        }
+    }
+}
+
+#[cfg(test)]
+mod find_path {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_walk_straight_across_open_map() {
+        let map = walls_map(vec!["....", "....", "...."]);
+        let path = map
+            .find_path(Point(0, 1), Point(3, 1), Connectivity::Four, floor_cost)
+            .unwrap();
+
+        assert_eq!(
+            path,
+            vec![Point(0, 1), Point(1, 1), Point(2, 1), Point(3, 1)]
+        );
+    }
+
+    #[test]
+    fn it_should_walk_around_walls() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            ".....",
+            ".###.",
+            ".....",
+        ]);
+        let path = map
+            .find_path(Point(0, 1), Point(4, 1), Connectivity::Four, floor_cost)
+            .unwrap();
+
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&Point(0, 1)));
+        assert_eq!(path.last(), Some(&Point(4, 1)));
+        assert_path_is_connected(&path, Connectivity::Four);
+    }
+
+    #[test]
+    fn it_should_walk_diagonally_with_eight_neighbours() {
+        let map = walls_map(vec!["....", "....", "....", "...."]);
+        let path = map
+            .find_path(Point(0, 0), Point(3, 3), Connectivity::Eight, floor_cost)
+            .unwrap();
+
+        assert_eq!(
+            path,
+            vec![Point(0, 0), Point(1, 1), Point(2, 2), Point(3, 3)]
+        );
+    }
+
+    #[test]
+    fn it_should_not_cut_corners() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            ".#",
+            "..",
+        ]);
+        let path = map
+            .find_path(Point(0, 0), Point(1, 1), Connectivity::Eight, floor_cost)
+            .unwrap();
+
+        assert_eq!(path, vec![Point(0, 0), Point(0, 1), Point(1, 1)]);
+    }
+
+    #[test]
+    fn it_should_prefer_cheaper_tiles() {
+        #[rustfmt::skip]
+        let map: Vec2D<u32> = Vec2D::new_from_vecs(vec![
+            vec![1, 9, 1],
+            vec![1, 1, 1],
+        ]);
+        let path = map
+            .find_path(Point(0, 0), Point(2, 0), Connectivity::Four, |_, cost| {
+                Some(cost as f32)
+            })
+            .unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                Point(0, 0),
+                Point(0, 1),
+                Point(1, 1),
+                Point(2, 1),
+                Point(2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_blocked() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "..#..",
+            "..#..",
+        ]);
+
+        assert_eq!(
+            map.find_path(Point(0, 0), Point(4, 0), Connectivity::Eight, floor_cost),
+            None
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_out_of_bounds() {
+        let map = walls_map(vec!["...."]);
+
+        assert_eq!(
+            map.find_path(Point(0, 0), Point(4, 0), Connectivity::Four, floor_cost),
+            None
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_for_empty_maps() {
+        for size in [Size(0, 0), Size(0, 3), Size(3, 0)] {
+            let map = Vec2D::new(size, false);
+
+            assert_eq!(
+                map.find_path(Point(0, 0), Point(0, 0), Connectivity::Four, floor_cost),
+                None,
+                "{:?}",
+                size
+            );
+            assert_eq!(
+                map.find_path_jps(Point(0, 0), Point(0, 0), |_, is_wall| !is_wall),
+                None,
+                "{:?}",
+                size
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod distance_map {
+    use super::*;
+    use crate::geom::Size;
+
+    #[test]
+    fn it_should_measure_distance_from_target() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "...",
+            ".#.",
+        ]);
+        let distances = map.distance_map(&[Point(0, 0)], Connectivity::Four, floor_cost);
+
+        assert_eq!(distances[Point(0, 0)], Some(0.0));
+        assert_eq!(distances[Point(0, 1)], Some(1.0));
+        assert_eq!(distances[Point(2, 1)], Some(3.0));
+        assert_eq!(distances[Point(2, 0)], Some(2.0));
+        assert_eq!(distances[Point(1, 0)], Some(1.0));
+        assert_eq!(distances[Point(1, 1)], None);
+    }
+
+    #[test]
+    fn it_should_use_nearest_of_many_targets() {
+        let map = walls_map(vec!["....."]);
+        let distances =
+            map.distance_map(&[Point(0, 0), Point(4, 0)], Connectivity::Four, floor_cost);
+
+        assert_eq!(
+            distances.raw_data(),
+            &[Some(0.0), Some(1.0), Some(2.0), Some(1.0), Some(0.0)]
+        );
+    }
+
+    #[test]
+    fn it_should_be_empty_for_empty_maps() {
+        for size in [Size(0, 0), Size(0, 3), Size(3, 0)] {
+            let map = Vec2D::new(size, false);
+            let distances = map.distance_map(&[Point(0, 0)], Connectivity::Four, floor_cost);
+
+            assert_eq!(distances.size(), size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod find_path_jps {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn is_floor(_: Point<usize>, is_wall: bool) -> bool {
+        !is_wall
+    }
+
+    #[test]
+    fn it_should_walk_diagonally_across_open_map() {
+        let map = walls_map(vec!["....", "....", "....", "...."]);
+        let path = map
+            .find_path_jps(Point(0, 0), Point(3, 3), is_floor)
+            .unwrap();
+
+        assert_eq!(
+            path,
+            vec![Point(0, 0), Point(1, 1), Point(2, 2), Point(3, 3)]
+        );
+    }
+
+    #[test]
+    fn it_should_find_same_length_path_as_a_star() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "..........",
+            ".######...",
+            "......#...",
+            ".####.#.#.",
+            "....#...#.",
+            "###.#####.",
+            "..........",
+        ]);
+        let from = Point(0, 0);
+        let to = Point(0, 6);
+
+        let jps_path = map.find_path_jps(from, to, is_floor).unwrap();
+        let a_star_path = map
+            .find_path(from, to, Connectivity::Eight, floor_cost)
+            .unwrap();
+
+        assert_eq!(jps_path.first(), Some(&from));
+        assert_eq!(jps_path.last(), Some(&to));
+        assert_path_is_connected(&jps_path, Connectivity::Eight);
+        assert_approx_eq!(path_cost(&jps_path), path_cost(&a_star_path));
+        for pos in jps_path {
+            assert!(!map[pos]);
+        }
+    }
+
+    #[test]
+    fn it_should_return_none_when_blocked() {
+        #[rustfmt::skip]
+        let map = walls_map(vec![
+            "..#..",
+            "..#..",
+        ]);
+
+        assert_eq!(map.find_path_jps(Point(0, 0), Point(4, 1), is_floor), None);
+    }
+
+    fn path_cost(path: &[Point<usize>]) -> f32 {
+        path.windows(2)
+            .map(|pair| octile_distance(pair[0], pair[1]))
+            .sum()
+    }
+}
@@ -0,0 +1,270 @@
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use super::Connectivity;
+use super::Vec2D;
+
+mod component_labels;
+pub use self::component_labels::*;
+
+mod component_region;
+pub use self::component_region::*;
+
+impl<V: Copy> Vec2D<V> {
+    /// Finds every tile connected to the start, which passes the predicate.
+    ///
+    /// Returns a mask where those tiles are true.
+    /// If the start does not pass the predicate, then nothing is filled.
+    pub fn flood_fill<F>(
+        &self,
+        start: Point<usize>,
+        connectivity: Connectivity,
+        mut predicate: F,
+    ) -> Vec2D<bool>
+    where
+        F: FnMut(Point<usize>, V) -> bool,
+    {
+        let mut filled = Vec2D::new(self.size(), false);
+        if !self.is_tile_in_bounds(start) {
+            return filled;
+        }
+
+        self.fill_from(
+            start,
+            connectivity,
+            &mut filled,
+            |pos| predicate(pos, self[pos]),
+            |_| {},
+        );
+
+        filled
+    }
+
+    /// Finds every separate region of connected tiles which pass the predicate.
+    ///
+    /// Each region is given an id, starting from 1.
+    /// Regions are numbered in the order they are found,
+    /// scanning from the bottom left.
+    pub fn label_components<F>(
+        &self,
+        connectivity: Connectivity,
+        mut predicate: F,
+    ) -> ComponentLabels
+    where
+        F: FnMut(Point<usize>, V) -> bool,
+    {
+        let mut labels = Vec2D::new(self.size(), 0);
+        let mut seen = Vec2D::new(self.size(), false);
+        let mut regions = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let start = Point(x, y);
+                if seen[start] {
+                    continue;
+                }
+
+                let id = regions.len() as u32 + 1;
+                let mut min = start;
+                let mut max = start;
+                let mut area = 0;
+
+                self.fill_from(
+                    start,
+                    connectivity,
+                    &mut seen,
+                    |pos| predicate(pos, self[pos]),
+                    |pos| {
+                        labels[pos] = id;
+                        min = min.min(pos);
+                        max = max.max(pos);
+                        area += 1;
+                    },
+                );
+
+                if area > 0 {
+                    let bounds = Rect(min, Size(max.x() - min.x() + 1, max.y() - min.y() + 1));
+                    regions.push(ComponentRegion::new(id, bounds, area));
+                }
+            }
+        }
+
+        ComponentLabels::new(labels, regions)
+    }
+
+    /// Visits every tile connected to the start which is inside,
+    /// marking them as seen.
+    ///
+    /// Tiles already seen are skipped.
+    fn fill_from<F, G>(
+        &self,
+        start: Point<usize>,
+        connectivity: Connectivity,
+        seen: &mut Vec2D<bool>,
+        mut is_inside: F,
+        mut on_tile: G,
+    ) where
+        F: FnMut(Point<usize>) -> bool,
+        G: FnMut(Point<usize>),
+    {
+        if !self.is_tile_in_bounds(start) || seen[start] || !is_inside(start) {
+            return;
+        }
+
+        seen[start] = true;
+        let mut stack = vec![start];
+
+        while let Some(pos) = stack.pop() {
+            on_tile(pos);
+
+            for step in connectivity.steps() {
+                let Some(next) = self.step_tile(pos, *step) else {
+                    continue;
+                };
+
+                if !seen[next] && is_inside(next) {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn caves_map(rows: Vec<&str>) -> Vec2D<bool> {
+    Vec2D::new_from_vecs(
+        rows.into_iter()
+            .map(|row| row.chars().map(|c| c == '.').collect())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+fn is_open(_: Point<usize>, is_open: bool) -> bool {
+    is_open
+}
+
+#[cfg(test)]
+mod flood_fill {
+    use super::*;
+
+    #[test]
+    fn it_should_fill_connected_tiles() {
+        #[rustfmt::skip]
+        let map = caves_map(vec![
+            "..#.",
+            "#.#.",
+            "..#.",
+        ]);
+        let filled = map.flood_fill(Point(0, 0), Connectivity::Four, is_open);
+
+        // The open tiles on the right are not connected.
+        #[rustfmt::skip]
+        let expected = caves_map(vec![
+            "..##",
+            "#.##",
+            "..##",
+        ]);
+        assert_eq!(filled, expected);
+    }
+
+    #[test]
+    fn it_should_only_cross_diagonals_with_eight_connectivity() {
+        #[rustfmt::skip]
+        let map = caves_map(vec![
+            ".#",
+            "#.",
+        ]);
+
+        let four = map.flood_fill(Point(0, 0), Connectivity::Four, is_open);
+        let eight = map.flood_fill(Point(0, 0), Connectivity::Eight, is_open);
+
+        assert!(!four[Point(1, 1)]);
+        assert!(eight[Point(1, 1)]);
+    }
+
+    #[test]
+    fn it_should_fill_nothing_when_start_fails_predicate() {
+        let map = caves_map(vec!["#.."]);
+        let filled = map.flood_fill(Point(0, 0), Connectivity::Four, is_open);
+
+        assert!(filled.iter().all(|(tile, _)| !tile));
+    }
+
+    #[test]
+    fn it_should_fill_nothing_when_start_is_outside() {
+        let map = caves_map(vec!["..."]);
+        let filled = map.flood_fill(Point(5, 0), Connectivity::Four, is_open);
+
+        assert!(filled.iter().all(|(tile, _)| !tile));
+    }
+
+    #[test]
+    fn it_should_do_nothing_on_empty_maps() {
+        for size in [Size(0, 0), Size(0, 3), Size(3, 0)] {
+            let map = Vec2D::new(size, false);
+            let filled = map.flood_fill(Point(0, 0), Connectivity::Eight, |_, _| true);
+
+            assert_eq!(filled.size(), size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod label_components {
+    use super::*;
+
+    #[test]
+    fn it_should_label_separate_regions() {
+        #[rustfmt::skip]
+        let map = caves_map(vec![
+            "..#.",
+            "#.#.",
+            "###.",
+            ".#..",
+        ]);
+        let components = map.label_components(Connectivity::Four, is_open);
+
+        #[rustfmt::skip]
+        let expected: Vec2D<u32> = Vec2D::new_from_vecs(vec![
+            vec![1, 1, 0, 2],
+            vec![0, 1, 0, 2],
+            vec![0, 0, 0, 2],
+            vec![3, 0, 2, 2],
+        ]);
+        assert_eq!(components.labels(), &expected);
+        assert_eq!(components.len(), 3);
+
+        let region = components.region(2).unwrap();
+        assert_eq!(region.area(), 5);
+        assert_eq!(region.bounds(), Rect(Point(2, 0), Size(2, 4)));
+
+        let region = components.region(3).unwrap();
+        assert_eq!(region.area(), 1);
+        assert_eq!(region.bounds(), Rect(Point(0, 3), Size(1, 1)));
+    }
+
+    #[test]
+    fn it_should_join_diagonals_with_eight_connectivity() {
+        #[rustfmt::skip]
+        let map = caves_map(vec![
+            ".#.",
+            "#.#",
+        ]);
+
+        assert_eq!(map.label_components(Connectivity::Four, is_open).len(), 3);
+        assert_eq!(map.label_components(Connectivity::Eight, is_open).len(), 1);
+    }
+
+    #[test]
+    fn it_should_return_none_for_region_zero() {
+        let map = caves_map(vec!["..."]);
+        let components = map.label_components(Connectivity::Four, is_open);
+
+        assert_eq!(components.region(0), None);
+        assert_eq!(components.region(2), None);
+        assert!(components.region(1).is_some());
+    }
+}
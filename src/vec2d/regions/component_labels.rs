@@ -0,0 +1,46 @@
+use crate::Vec2D;
+
+use super::ComponentRegion;
+
+/// The result of `Vec2D::label_components`.
+#[derive(Clone, Debug)]
+pub struct ComponentLabels {
+    labels: Vec2D<u32>,
+    regions: Vec<ComponentRegion>,
+}
+
+impl ComponentLabels {
+    pub(crate) fn new(labels: Vec2D<u32>, regions: Vec<ComponentRegion>) -> Self {
+        Self { labels, regions }
+    }
+
+    /// The id of the region each tile is in.
+    /// Tiles which are not in any region are 0.
+    pub fn labels(&self) -> &Vec2D<u32> {
+        &self.labels
+    }
+
+    pub fn into_labels(self) -> Vec2D<u32> {
+        self.labels
+    }
+
+    /// Every region found, in order of their id.
+    pub fn regions(&self) -> &[ComponentRegion] {
+        &self.regions
+    }
+
+    /// Returns the region with the id given.
+    pub fn region(&self, id: u32) -> Option<&ComponentRegion> {
+        let index = (id as usize).checked_sub(1)?;
+
+        self.regions.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::geom::Rect;
+
+/// A single connected region found by `Vec2D::label_components`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ComponentRegion {
+    id: u32,
+    bounds: Rect<usize>,
+    area: usize,
+}
+
+impl ComponentRegion {
+    pub(crate) fn new(id: u32, bounds: Rect<usize>, area: usize) -> Self {
+        Self { id, bounds, area }
+    }
+
+    /// The id used for this region, in the labels.
+    /// Ids start from 1.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The smallest area which covers every tile in the region.
+    pub fn bounds(&self) -> Rect<usize> {
+        self.bounds
+    }
+
+    /// The number of tiles in the region.
+    pub fn area(&self) -> usize {
+        self.area
+    }
+}
@@ -0,0 +1,423 @@
+use crate::geom::HorizontalPosition;
+use crate::geom::Point;
+use crate::geom::PointPosition;
+use crate::geom::Rect;
+use crate::geom::Size;
+use crate::geom::VerticalPosition;
+
+use super::map_index;
+use super::Vec2D;
+
+impl<V: Copy> Vec2D<V> {
+    /// Changes the size, keeping the existing tiles where the anchor says.
+    ///
+    /// For example anchoring with `HorizontalPosition::Left` keeps the tiles on the left,
+    /// and grows or shrinks the right hand side.
+    /// Anchoring with `Inside` keeps the tiles in the middle.
+    ///
+    /// New tiles are set to the fill value.
+    pub fn resize(&mut self, new_size: Size<usize>, fill: V, anchor: PointPosition) {
+        let offset_x = anchor_offset(
+            self.width,
+            new_size.width(),
+            match anchor.horizontal() {
+                HorizontalPosition::Left => Anchor::Start,
+                HorizontalPosition::Inside => Anchor::Middle,
+                HorizontalPosition::Right => Anchor::End,
+            },
+        );
+        let offset_y = anchor_offset(
+            self.height,
+            new_size.height(),
+            match anchor.vertical() {
+                VerticalPosition::Below => Anchor::Start,
+                VerticalPosition::Inside => Anchor::Middle,
+                VerticalPosition::Above => Anchor::End,
+            },
+        );
+
+        let mut resized = Vec2D::new(new_size, fill);
+        for y in 0..new_size.height() {
+            for x in 0..new_size.width() {
+                let old_x = x as isize - offset_x;
+                let old_y = y as isize - offset_y;
+
+                if old_x >= 0
+                    && old_y >= 0
+                    && (old_x as usize) < self.width
+                    && (old_y as usize) < self.height
+                {
+                    resized[Point(x, y)] = self[Point(old_x as usize, old_y as usize)];
+                }
+            }
+        }
+
+        *self = resized;
+    }
+
+    /// Cuts this down to only the area given.
+    ///
+    /// The area is clipped to fit within this.
+    pub fn crop(&mut self, area: Rect<usize>) {
+        let crop_area = self.clip_area(area);
+        let mut data = Vec::with_capacity(crop_area.area());
+
+        for y in crop_area.bottom_y()..crop_area.top_y() {
+            let start = map_index(Point(crop_area.left_x(), y), self.width);
+            data.extend_from_slice(&self.data[start..start + crop_area.width()]);
+        }
+
+        self.width = crop_area.width();
+        self.height = crop_area.height();
+        self.data = data;
+    }
+
+    /// Inserts a new row at the y position given, set to the fill value.
+    /// The rows at and above it are moved up.
+    ///
+    /// Panics if y is greater than the height.
+    pub fn insert_row(&mut self, y: usize, fill: V) {
+        assert!(
+            y <= self.height,
+            "insert_row y {} is past the height {}",
+            y,
+            self.height
+        );
+
+        let index = y * self.width;
+        self.data
+            .splice(index..index, std::iter::repeat_n(fill, self.width));
+        self.height += 1;
+    }
+
+    /// Inserts a new column at the x position given, set to the fill value.
+    /// The columns at and to the right of it are moved right.
+    ///
+    /// Panics if x is greater than the width.
+    pub fn insert_column(&mut self, x: usize, fill: V) {
+        assert!(
+            x <= self.width,
+            "insert_column x {} is past the width {}",
+            x,
+            self.width
+        );
+
+        let new_width = self.width + 1;
+        let mut data = Vec::with_capacity(new_width * self.height);
+        for row in self.rows() {
+            data.extend_from_slice(&row[..x]);
+            data.push(fill);
+            data.extend_from_slice(&row[x..]);
+        }
+
+        self.width = new_width;
+        self.data = data;
+    }
+
+    /// Removes the row at the y position given, and returns it.
+    /// The rows above it are moved down.
+    ///
+    /// Panics if y is outside of this.
+    pub fn remove_row(&mut self, y: usize) -> Vec<V> {
+        assert!(
+            y < self.height,
+            "remove_row y {} is outside of the height {}",
+            y,
+            self.height
+        );
+
+        let index = y * self.width;
+        let removed = self.data.drain(index..index + self.width).collect();
+        self.height -= 1;
+
+        removed
+    }
+
+    /// Removes the column at the x position given, and returns it.
+    /// The columns to the right of it are moved left.
+    ///
+    /// Panics if x is outside of this.
+    pub fn remove_column(&mut self, x: usize) -> Vec<V> {
+        assert!(
+            x < self.width,
+            "remove_column x {} is outside of the width {}",
+            x,
+            self.width
+        );
+
+        let removed = self.column(x).copied().collect();
+        let mut data = Vec::with_capacity((self.width - 1) * self.height);
+        for row in self.rows() {
+            data.extend_from_slice(&row[..x]);
+            data.extend_from_slice(&row[x + 1..]);
+        }
+
+        self.width -= 1;
+        self.data = data;
+
+        removed
+    }
+
+    /// Copies the other `Vec2D` into this one, with its bottom left at the position given.
+    ///
+    /// Anything which falls outside of this is skipped.
+    pub fn blit(&mut self, other: &Vec2D<V>, at: Point<usize>) {
+        let Some(area) = self.rect().intersect_rect(Rect(at, other.size())) else {
+            return;
+        };
+
+        for y in area.bottom_y()..area.top_y() {
+            let src_start = map_index(Point(area.left_x() - at.x(), y - at.y()), other.width);
+            let dest_start = map_index(Point(area.left_x(), y), self.width);
+            let width = area.width();
+
+            self.data[dest_start..dest_start + width]
+                .copy_from_slice(&other.data[src_start..src_start + width]);
+        }
+    }
+}
+
+enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// How far the old tiles are moved along, when going from the old length to the new.
+fn anchor_offset(old_length: usize, new_length: usize, anchor: Anchor) -> isize {
+    let diff = new_length as isize - old_length as isize;
+
+    match anchor {
+        Anchor::Start => 0,
+        Anchor::Middle => diff / 2,
+        Anchor::End => diff,
+    }
+}
+
+#[cfg(test)]
+mod resize {
+    use super::*;
+
+    #[rustfmt::skip]
+    fn new_test_vec2d() -> Vec2D<u32> {
+        Vec2D::new_from_vecs(vec![
+            vec![1, 2],
+            vec![3, 4],
+        ])
+    }
+
+    #[test]
+    fn it_should_grow_up_and_right_when_anchored_bottom_left() {
+        let mut vec2d = new_test_vec2d();
+        vec2d.resize(
+            Size(3, 3),
+            0,
+            PointPosition(HorizontalPosition::Left, VerticalPosition::Below),
+        );
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![1, 2, 0],
+            vec![3, 4, 0],
+            vec![0, 0, 0],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_grow_down_and_left_when_anchored_top_right() {
+        let mut vec2d = new_test_vec2d();
+        vec2d.resize(
+            Size(3, 3),
+            0,
+            PointPosition(HorizontalPosition::Right, VerticalPosition::Above),
+        );
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0],
+            vec![0, 1, 2],
+            vec![0, 3, 4],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_grow_on_all_sides_when_anchored_inside() {
+        let mut vec2d = new_test_vec2d();
+        vec2d.resize(
+            Size(4, 4),
+            0,
+            PointPosition(HorizontalPosition::Inside, VerticalPosition::Inside),
+        );
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0, 0],
+            vec![0, 1, 2, 0],
+            vec![0, 3, 4, 0],
+            vec![0, 0, 0, 0],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_shrink_from_the_side_not_anchored() {
+        let mut vec2d = new_test_vec2d();
+        vec2d.resize(
+            Size(1, 2),
+            0,
+            PointPosition(HorizontalPosition::Right, VerticalPosition::Below),
+        );
+
+        assert_eq!(vec2d, Vec2D::new_from_vecs(vec![vec![2], vec![4]]));
+    }
+}
+
+#[cfg(test)]
+mod crop {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_only_area_given() {
+        #[rustfmt::skip]
+        let mut vec2d = Vec2D::new_from_vecs(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+        ]);
+        vec2d.crop(Rect(Point(1, 1), Size(5, 5)));
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![5, 6],
+            vec![8, 9],
+        ]);
+        assert_eq!(vec2d, expected);
+        assert_eq!(vec2d.size(), Size(2, 2));
+    }
+
+    #[test]
+    fn it_should_become_empty_when_outside() {
+        let mut vec2d = Vec2D::new(Size(3, 3), 1);
+        vec2d.crop(Rect(Point(5, 5), Size(2, 2)));
+
+        assert_eq!(vec2d.size(), Size(0, 0));
+    }
+}
+
+#[cfg(test)]
+mod insert_row {
+    use super::*;
+
+    #[test]
+    fn it_should_move_rows_above_up() {
+        let mut vec2d = Vec2D::new_from_vecs(vec![vec![1, 2], vec![3, 4]]);
+        vec2d.insert_row(1, 0);
+
+        assert_eq!(
+            vec2d,
+            Vec2D::new_from_vecs(vec![vec![1, 2], vec![0, 0], vec![3, 4]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_past_the_height() {
+        let mut vec2d = Vec2D::new(Size(2, 2), 0);
+        vec2d.insert_row(3, 0);
+    }
+}
+
+#[cfg(test)]
+mod insert_column {
+    use super::*;
+
+    #[test]
+    fn it_should_add_column_at_the_end() {
+        let mut vec2d = Vec2D::new_from_vecs(vec![vec![1, 2], vec![3, 4]]);
+        vec2d.insert_column(2, 0);
+
+        assert_eq!(
+            vec2d,
+            Vec2D::new_from_vecs(vec![vec![1, 2, 0], vec![3, 4, 0]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod remove_row {
+    use super::*;
+
+    #[test]
+    fn it_should_return_removed_row() {
+        let mut vec2d = Vec2D::new_from_vecs(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+
+        assert_eq!(vec2d.remove_row(1), vec![3, 4]);
+        assert_eq!(vec2d, Vec2D::new_from_vecs(vec![vec![1, 2], vec![5, 6]]));
+    }
+}
+
+#[cfg(test)]
+mod remove_column {
+    use super::*;
+
+    #[test]
+    fn it_should_return_removed_column() {
+        let mut vec2d = Vec2D::new_from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(vec2d.remove_column(0), vec![1, 4]);
+        assert_eq!(vec2d, Vec2D::new_from_vecs(vec![vec![2, 3], vec![5, 6]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_outside_of_width() {
+        let mut vec2d = Vec2D::new(Size(2, 2), 0);
+        vec2d.remove_column(2);
+    }
+}
+
+#[cfg(test)]
+mod blit {
+    use super::*;
+
+    #[test]
+    fn it_should_copy_other_at_position() {
+        let mut vec2d = Vec2D::new(Size(4, 3), 0);
+        let stamp = Vec2D::new_from_vecs(vec![vec![1, 2], vec![3, 4]]);
+        vec2d.blit(&stamp, Point(1, 1));
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0, 0],
+            vec![0, 1, 2, 0],
+            vec![0, 3, 4, 0],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_clip_to_the_edges() {
+        let mut vec2d = Vec2D::new(Size(3, 3), 0);
+        let stamp = Vec2D::new_from_vecs(vec![vec![1, 2], vec![3, 4]]);
+        vec2d.blit(&stamp, Point(2, 2));
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0],
+            vec![0, 0, 0],
+            vec![0, 0, 1],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+
+    #[test]
+    fn it_should_do_nothing_when_outside() {
+        let mut vec2d = Vec2D::new(Size(3, 3), 0);
+        let stamp = Vec2D::new(Size(2, 2), 1);
+        vec2d.blit(&stamp, Point(5, 5));
+
+        assert_eq!(vec2d, Vec2D::new(Size(3, 3), 0));
+    }
+}
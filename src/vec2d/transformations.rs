@@ -0,0 +1,250 @@
+use crate::geom::Point;
+use crate::geom::Size;
+
+use super::Vec2D;
+
+impl<V: Copy> Vec2D<V> {
+    /// Returns a copy rotated a quarter turn clockwise.
+    /// This matches the direction of `Point::rotate_around_zero`.
+    pub fn rotate_90(&self) -> Self {
+        self.remap(self.size().swizzle_hw(), |Point(x, y)| {
+            Point(y, self.width - 1 - x)
+        })
+    }
+
+    /// Returns a copy rotated a half turn.
+    pub fn rotate_180(&self) -> Self {
+        self.remap(self.size(), |Point(x, y)| {
+            Point(self.width - 1 - x, self.height - 1 - y)
+        })
+    }
+
+    /// Returns a copy rotated three quarters of a turn clockwise.
+    /// This is the same as a quarter turn anti-clockwise.
+    pub fn rotate_270(&self) -> Self {
+        self.remap(self.size().swizzle_hw(), |Point(x, y)| {
+            Point(self.height - 1 - y, x)
+        })
+    }
+
+    /// Returns a copy mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(self.size(), |Point(x, y)| Point(self.width - 1 - x, y))
+    }
+
+    /// Returns a copy mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(self.size(), |Point(x, y)| Point(x, self.height - 1 - y))
+    }
+
+    /// Returns a copy with the x and y axis swapped.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.size().swizzle_hw(), |Point(x, y)| Point(y, x))
+    }
+
+    /// Returns a new `Vec2D` of the same size, with every tile passed through the function.
+    pub fn map<U: Copy, F>(&self, f: F) -> Vec2D<U>
+    where
+        F: FnMut(V) -> U,
+    {
+        Vec2D {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().copied().map(f).collect(),
+        }
+    }
+
+    /// Returns a new `Vec2D` made by combining each tile with the tile at the same position in the other.
+    ///
+    /// Panics if the two are different sizes.
+    pub fn zip_map<W: Copy, U: Copy, F>(&self, other: &Vec2D<W>, mut f: F) -> Vec2D<U>
+    where
+        F: FnMut(V, W) -> U,
+    {
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "zip_map requires both Vec2Ds to be the same size"
+        );
+
+        Vec2D {
+            width: self.width,
+            height: self.height,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+        }
+    }
+
+    /// Builds a new `Vec2D` of the size given,
+    /// where each tile at a position is moved to the position returned.
+    fn remap<F>(&self, new_size: Size<usize>, to_new_pos: F) -> Self
+    where
+        F: Fn(Point<usize>) -> Point<usize>,
+    {
+        let mut data = self.data.clone();
+        for (tile, pos) in self {
+            let Point(new_x, new_y) = to_new_pos(pos);
+            data[new_y * new_size.width() + new_x] = tile;
+        }
+
+        Vec2D {
+            width: new_size.width(),
+            height: new_size.height(),
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip]
+fn new_test_vec2d() -> Vec2D<u32> {
+    // Rows are listed bottom first.
+    Vec2D::new_from_vecs(vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+    ])
+}
+
+#[cfg(test)]
+mod rotate_90 {
+    use super::*;
+
+    #[test]
+    fn it_should_rotate_clockwise() {
+        let rotated = new_test_vec2d().rotate_90();
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![3, 6],
+            vec![2, 5],
+            vec![1, 4],
+        ]);
+        assert_eq!(rotated, expected);
+        assert_eq!(rotated.size(), Size(2, 3));
+    }
+
+    #[test]
+    fn it_should_match_point_rotation() {
+        let rotated = new_test_vec2d().rotate_90();
+
+        // The bottom left corner moves to the top left.
+        assert_eq!(rotated[Point(0, 2)], 1);
+    }
+
+    #[test]
+    fn it_should_return_original_after_four_turns() {
+        let vec2d = new_test_vec2d();
+
+        assert_eq!(vec2d.rotate_90().rotate_90().rotate_90().rotate_90(), vec2d);
+    }
+}
+
+#[cfg(test)]
+mod rotate_180 {
+    use super::*;
+
+    #[test]
+    fn it_should_match_two_quarter_turns() {
+        let vec2d = new_test_vec2d();
+
+        assert_eq!(vec2d.rotate_180(), vec2d.rotate_90().rotate_90());
+        assert_eq!(
+            vec2d.rotate_180(),
+            Vec2D::new_from_vecs(vec![vec![6, 5, 4], vec![3, 2, 1]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod rotate_270 {
+    use super::*;
+
+    #[test]
+    fn it_should_undo_rotate_90() {
+        let vec2d = new_test_vec2d();
+
+        assert_eq!(vec2d.rotate_90().rotate_270(), vec2d);
+        assert_eq!(vec2d.rotate_270(), vec2d.rotate_180().rotate_90());
+    }
+}
+
+#[cfg(test)]
+mod flip_horizontal {
+    use super::*;
+
+    #[test]
+    fn it_should_mirror_left_to_right() {
+        assert_eq!(
+            new_test_vec2d().flip_horizontal(),
+            Vec2D::new_from_vecs(vec![vec![3, 2, 1], vec![6, 5, 4]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod flip_vertical {
+    use super::*;
+
+    #[test]
+    fn it_should_mirror_top_to_bottom() {
+        assert_eq!(
+            new_test_vec2d().flip_vertical(),
+            Vec2D::new_from_vecs(vec![vec![4, 5, 6], vec![1, 2, 3]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod transpose {
+    use super::*;
+
+    #[test]
+    fn it_should_swap_axis() {
+        assert_eq!(
+            new_test_vec2d().transpose(),
+            Vec2D::new_from_vecs(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod map {
+    use super::*;
+
+    #[test]
+    fn it_should_map_every_tile() {
+        let mapped = new_test_vec2d().map(|n| n % 2 == 0);
+
+        assert_eq!(
+            mapped,
+            Vec2D::new_from_vecs(vec![vec![false, true, false], vec![true, false, true]])
+        );
+    }
+}
+
+#[cfg(test)]
+mod zip_map {
+    use super::*;
+
+    #[test]
+    fn it_should_combine_tiles_at_same_position() {
+        let vec2d = new_test_vec2d();
+        let zipped = vec2d.zip_map(&vec2d.flip_horizontal(), |a, b| a + b);
+
+        assert_eq!(
+            zipped,
+            Vec2D::new_from_vecs(vec![vec![4, 4, 4], vec![10, 10, 10]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_sizes_differ() {
+        let vec2d = new_test_vec2d();
+        vec2d.zip_map(&vec2d.transpose(), |a, b| a + b);
+    }
+}
@@ -0,0 +1,68 @@
+use std::iter::Iterator;
+
+use crate::geom::Point;
+use crate::geom::Rect;
+
+use super::map_index;
+
+/// A mutable iterator for the `Vec2D`.
+pub struct Vec2DIterMut<'a, V: 'a> {
+    /// The raw data which has not yet been iterated over.
+    data: &'a mut [V],
+
+    /// The size of the data when 2D.
+    /// Needed for translating index.
+    data_width: usize,
+
+    /// The size of the area we are iterating over.
+    iterate_area: Rect<usize>,
+
+    /// The index in the `Vec2D` of the start of `data`.
+    index: usize,
+}
+
+impl<'a, V> Vec2DIterMut<'a, V> {
+    /// The area must already be clipped to fit within the data.
+    pub(crate) fn new(data: &'a mut [V], data_width: usize, iterate_area: Rect<usize>) -> Self {
+        if iterate_area.area() == 0 {
+            return Self {
+                data: &mut [],
+                data_width,
+                iterate_area,
+                index: 0,
+            };
+        }
+
+        let start = map_index(iterate_area.bottom_left(), data_width);
+        let end = map_index(
+            Point(iterate_area.right_x(), iterate_area.top_y() - 1),
+            data_width,
+        );
+
+        Self {
+            data: &mut data[start..end],
+            data_width,
+            iterate_area,
+            index: start,
+        }
+    }
+}
+
+impl<'a, V> Iterator for Vec2DIterMut<'a, V> {
+    type Item = (&'a mut V, Point<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (first, rest) = std::mem::take(&mut self.data).split_first_mut()?;
+            self.data = rest;
+
+            let pos = Point(self.index % self.data_width, self.index / self.data_width);
+            self.index += 1;
+
+            // Skip over the parts of each row outside of the area.
+            if pos.x() >= self.iterate_area.left_x() && pos.x() < self.iterate_area.right_x() {
+                return Some((first, pos));
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::Index;
+
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use super::Vec2D;
+
+/// A borrowed window onto part of a `Vec2D`.
+///
+/// Positions are relative to the bottom left of the window.
+#[derive(Copy, Clone)]
+pub struct Vec2DView<'a, V: Copy> {
+    data: &'a Vec2D<V>,
+
+    /// The area of the `Vec2D` this is looking at.
+    area: Rect<usize>,
+}
+
+impl<'a, V: Copy> Vec2DView<'a, V> {
+    /// The area must already be clipped to fit within the data.
+    pub(crate) fn new(data: &'a Vec2D<V>, area: Rect<usize>) -> Self {
+        Self { data, area }
+    }
+
+    /// Returns the tile at the position given, relative to this view.
+    pub fn get(&self, pos: Point<usize>) -> Option<&'a V> {
+        if pos.x() >= self.width() || pos.y() >= self.height() {
+            return None;
+        }
+
+        self.data.get(self.offset() + pos)
+    }
+
+    /// Returns the size of this view as a Rect.
+    /// The bottom left corner is always Point(0, 0).
+    pub fn rect(&self) -> Rect<usize> {
+        Rect(Point(0, 0), self.size())
+    }
+
+    pub fn size(&self) -> Size<usize> {
+        self.area.size()
+    }
+
+    pub fn width(&self) -> usize {
+        self.area.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.area.height()
+    }
+
+    /// Where the bottom left of this view is, within the `Vec2D`.
+    pub fn offset(&self) -> Point<usize> {
+        self.area.bottom_left()
+    }
+
+    /// Iterates over the tiles in this view,
+    /// with positions relative to this view.
+    pub fn iter(&self) -> impl Iterator<Item = (V, Point<usize>)> + 'a {
+        let offset = self.offset();
+
+        self.data
+            .iter_of(self.area)
+            .map(move |(tile, pos)| (tile, pos - offset))
+    }
+}
+
+impl<'a, V: Copy> Index<Point<usize>> for Vec2DView<'a, V> {
+    type Output = V;
+
+    fn index(&self, pos: Point<usize>) -> &V {
+        self.get(pos).expect("Position is outside of the Vec2DView")
+    }
+}
+
+#[cfg(test)]
+mod index {
+    use super::*;
+
+    #[test]
+    fn it_should_index_relative_to_view() {
+        let mut vec2d = Vec2D::new(Size(5, 5), Point(0, 0));
+        for (tile, pos) in vec2d.iter_mut() {
+            *tile = pos;
+        }
+
+        let view = vec2d.view(Rect(Point(2, 1), Size(2, 3)));
+
+        assert_eq!(view[Point(0, 0)], Point(2, 1));
+        assert_eq!(view[Point(1, 2)], Point(3, 3));
+        assert_eq!(view.get(Point(2, 0)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_outside_of_view() {
+        let vec2d = Vec2D::new(Size(5, 5), 0);
+        let view = vec2d.view(Rect(Point(0, 0), Size(2, 2)));
+
+        let _ = view[Point(3, 3)];
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_should_clip_to_vec2d() {
+        let vec2d = Vec2D::new(Size(5, 5), 0);
+        let view = vec2d.view(Rect(Point(3, 4), Size(10, 10)));
+
+        assert_eq!(view.size(), Size(2, 1));
+        assert_eq!(view.offset(), Point(3, 4));
+    }
+}
+
+#[cfg(test)]
+mod iter {
+    use super::*;
+
+    #[test]
+    fn it_should_return_positions_relative_to_view() {
+        let vec2d = Vec2D::new(Size(5, 5), 1);
+        let view = vec2d.view(Rect(Point(3, 3), Size(2, 2)));
+        let positions: Vec<Point<usize>> = view.iter().map(|(_, pos)| pos).collect();
+
+        assert_eq!(
+            positions,
+            vec![Point(0, 0), Point(1, 0), Point(0, 1), Point(1, 1)]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use crate::geom::Point;
+use crate::geom::Rect;
+use crate::geom::Size;
+
+use super::Vec2D;
+use super::Vec2DIterMut;
+use super::Vec2DView;
+
+/// A mutably borrowed window onto part of a `Vec2D`.
+///
+/// Positions are relative to the bottom left of the window.
+pub struct Vec2DViewMut<'a, V: Copy> {
+    data: &'a mut Vec2D<V>,
+
+    /// The area of the `Vec2D` this is looking at.
+    area: Rect<usize>,
+}
+
+impl<'a, V: Copy> Vec2DViewMut<'a, V> {
+    /// The area must already be clipped to fit within the data.
+    pub(crate) fn new(data: &'a mut Vec2D<V>, area: Rect<usize>) -> Self {
+        Self { data, area }
+    }
+
+    /// Returns the tile at the position given, relative to this view.
+    pub fn get(&self, pos: Point<usize>) -> Option<&V> {
+        if !self.is_inside(pos) {
+            return None;
+        }
+
+        self.data.get(self.offset() + pos)
+    }
+
+    /// Returns the tile at the position given, relative to this view.
+    pub fn get_mut(&mut self, pos: Point<usize>) -> Option<&mut V> {
+        if !self.is_inside(pos) {
+            return None;
+        }
+
+        let offset = self.offset();
+        Some(&mut self.data[offset + pos])
+    }
+
+    /// Sets a tile at the position given, relative to this view.
+    pub fn set(&mut self, pos: Point<usize>, value: V) {
+        self[pos] = value;
+    }
+
+    /// Sets every tile in this view to the value given.
+    pub fn fill(&mut self, value: V) {
+        for (tile, _) in self.iter_mut() {
+            *tile = value;
+        }
+    }
+
+    /// Returns the size of this view as a Rect.
+    /// The bottom left corner is always Point(0, 0).
+    pub fn rect(&self) -> Rect<usize> {
+        Rect(Point(0, 0), self.size())
+    }
+
+    pub fn size(&self) -> Size<usize> {
+        self.area.size()
+    }
+
+    pub fn width(&self) -> usize {
+        self.area.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.area.height()
+    }
+
+    /// Where the bottom left of this view is, within the `Vec2D`.
+    pub fn offset(&self) -> Point<usize> {
+        self.area.bottom_left()
+    }
+
+    /// Iterates over the tiles in this view,
+    /// with positions relative to this view.
+    pub fn iter(&self) -> impl Iterator<Item = (V, Point<usize>)> + '_ {
+        self.as_view().iter()
+    }
+
+    /// Mutably iterates over the tiles in this view,
+    /// with positions relative to this view.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&mut V, Point<usize>)> {
+        let offset = self.offset();
+        let iter: Vec2DIterMut<V> = self.data.iter_of_mut(self.area);
+
+        iter.map(move |(tile, pos)| (tile, pos - offset))
+    }
+
+    pub fn as_view(&self) -> Vec2DView<'_, V> {
+        Vec2DView::new(self.data, self.area)
+    }
+
+    fn is_inside(&self, pos: Point<usize>) -> bool {
+        pos.x() < self.width() && pos.y() < self.height()
+    }
+}
+
+impl<'a, V: Copy> Index<Point<usize>> for Vec2DViewMut<'a, V> {
+    type Output = V;
+
+    fn index(&self, pos: Point<usize>) -> &V {
+        self.get(pos)
+            .expect("Position is outside of the Vec2DViewMut")
+    }
+}
+
+impl<'a, V: Copy> IndexMut<Point<usize>> for Vec2DViewMut<'a, V> {
+    fn index_mut(&mut self, pos: Point<usize>) -> &mut V {
+        self.get_mut(pos)
+            .expect("Position is outside of the Vec2DViewMut")
+    }
+}
+
+#[cfg(test)]
+mod index_mut {
+    use super::*;
+
+    #[test]
+    fn it_should_set_relative_to_view() {
+        let mut vec2d = Vec2D::new(Size(4, 4), 0);
+        let mut view = vec2d.view_mut(Rect(Point(1, 2), Size(2, 2)));
+        view[Point(1, 1)] = 5;
+
+        assert_eq!(vec2d[Point(2, 3)], 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_outside_of_view() {
+        let mut vec2d = Vec2D::new(Size(4, 4), 0);
+        let mut view = vec2d.view_mut(Rect(Point(0, 0), Size(2, 2)));
+
+        view[Point(2, 0)] = 1;
+    }
+}
+
+#[cfg(test)]
+mod fill {
+    use super::*;
+
+    #[test]
+    fn it_should_only_fill_inside_view() {
+        let mut vec2d = Vec2D::new(Size(3, 3), 0);
+        vec2d.view_mut(Rect(Point(1, 1), Size(5, 5))).fill(1);
+
+        #[rustfmt::skip]
+        let expected = Vec2D::new_from_vecs(vec![
+            vec![0, 0, 0],
+            vec![0, 1, 1],
+            vec![0, 1, 1],
+        ]);
+        assert_eq!(vec2d, expected);
+    }
+}